@@ -0,0 +1,42 @@
+/* C interface of the inin crate, built with the `ffi` feature; see README.md. */
+#ifndef ININ_H
+#define ININ_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Return codes of the inin_*_is_valid functions. */
+#define ININ_OK 0
+/* A null pointer was passed. */
+#define ININ_ERR_NULL 1
+/* The input is not valid UTF-8. */
+#define ININ_ERR_UTF8 2
+/* The input has the wrong number of digits or characters. */
+#define ININ_ERR_LENGTH 3
+/* The input contains a character that is not allowed. */
+#define ININ_ERR_CHARACTER 4
+/* The check digit or checksum does not match. */
+#define ININ_ERR_CHECKSUM 5
+/* The input is well formed but rejected for another reason, such as an all-zero id
+   or a non-Iranian IBAN. */
+#define ININ_ERR_INVALID 6
+
+/* Validates a NUL-terminated UTF-8 national id, returning ININ_OK or an ININ_ERR_* code. */
+int inin_national_id_is_valid(const char *input);
+
+/* Validates a NUL-terminated UTF-8 Sheba (Iranian IBAN), returning ININ_OK or an ININ_ERR_* code. */
+int inin_sheba_is_valid(const char *input);
+
+/* Returns the canonical ten-digit form of a national id, or NULL if it is invalid.
+   The result must be released with inin_string_free. */
+char *inin_national_id_normalize(const char *input);
+
+/* Releases a string returned by this library. Passing NULL is a no-op. */
+void inin_string_free(char *value);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ININ_H */
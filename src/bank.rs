@@ -0,0 +1,192 @@
+use core::fmt::{Display, Formatter};
+
+/// Iranian banks and credit institutions, as identified by their central bank code.
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum Bank {
+    Markazi,
+    SanatVaMadan,
+    Mellat,
+    Refah,
+    Maskan,
+    Sepah,
+    Keshavarzi,
+    Melli,
+    Tejarat,
+    Saderat,
+    ToseeSaderat,
+    PostBank,
+    ToseeTaavon,
+    ToseeCredit,
+    Karafarin,
+    Parsian,
+    EghtesadNovin,
+    Saman,
+    Pasargad,
+    Sarmayeh,
+    Sina,
+    MehrIran,
+    Shahr,
+    Ayandeh,
+    Ansar,
+    Gardeshgari,
+    Hekmat,
+    Dey,
+    IranZamin,
+    Resalat,
+    Kosar,
+    Melal,
+    Khavarmianeh,
+    Noor,
+    IranVenezuela,
+}
+
+/// (bank, three-digit code, english name, persian name)
+const BANKS: [(Bank, &str, &str, &str); 35] = [
+    (Bank::Markazi, "010", "Central Bank of Iran", "بانک مرکزی جمهوری اسلامی ایران"),
+    (Bank::SanatVaMadan, "011", "Bank of Industry and Mine", "بانک صنعت و معدن"),
+    (Bank::Mellat, "012", "Bank Mellat", "بانک ملت"),
+    (Bank::Refah, "013", "Refah Kargaran Bank", "بانک رفاه کارگران"),
+    (Bank::Maskan, "014", "Bank Maskan", "بانک مسکن"),
+    (Bank::Sepah, "015", "Bank Sepah", "بانک سپه"),
+    (Bank::Keshavarzi, "016", "Bank Keshavarzi", "بانک کشاورزی"),
+    (Bank::Melli, "017", "Bank Melli Iran", "بانک ملی ایران"),
+    (Bank::Tejarat, "018", "Tejarat Bank", "بانک تجارت"),
+    (Bank::Saderat, "019", "Bank Saderat Iran", "بانک صادرات ایران"),
+    (Bank::ToseeSaderat, "020", "Export Development Bank of Iran", "بانک توسعه صادرات ایران"),
+    (Bank::PostBank, "021", "Post Bank of Iran", "پست بانک ایران"),
+    (Bank::ToseeTaavon, "022", "Tose'e Ta'avon Bank", "بانک توسعه تعاون"),
+    (Bank::ToseeCredit, "051", "Tose'e Credit Institution", "موسسه اعتباری توسعه"),
+    (Bank::Karafarin, "053", "Karafarin Bank", "بانک کارآفرین"),
+    (Bank::Parsian, "054", "Parsian Bank", "بانک پارسیان"),
+    (Bank::EghtesadNovin, "055", "Bank Eghtesad Novin", "بانک اقتصاد نوین"),
+    (Bank::Saman, "056", "Saman Bank", "بانک سامان"),
+    (Bank::Pasargad, "057", "Bank Pasargad", "بانک پاسارگاد"),
+    (Bank::Sarmayeh, "058", "Sarmayeh Bank", "بانک سرمایه"),
+    (Bank::Sina, "059", "Sina Bank", "بانک سینا"),
+    (Bank::MehrIran, "060", "Gharzolhasaneh Mehr Iran Bank", "بانک قرض‌الحسنه مهر ایران"),
+    (Bank::Shahr, "061", "Shahr Bank", "بانک شهر"),
+    (Bank::Ayandeh, "062", "Ayandeh Bank", "بانک آینده"),
+    (Bank::Ansar, "063", "Ansar Bank", "بانک انصار"),
+    (Bank::Gardeshgari, "064", "Tourism Bank", "بانک گردشگری"),
+    (Bank::Hekmat, "065", "Hekmat Iranian Bank", "بانک حکمت ایرانیان"),
+    (Bank::Dey, "066", "Dey Bank", "بانک دی"),
+    (Bank::IranZamin, "069", "Iran Zamin Bank", "بانک ایران زمین"),
+    (Bank::Resalat, "070", "Gharzolhasaneh Resalat Bank", "بانک قرض‌الحسنه رسالت"),
+    (Bank::Kosar, "073", "Kosar Credit Institution", "موسسه اعتباری کوثر"),
+    (Bank::Melal, "075", "Melal Credit Institution", "موسسه اعتباری ملل"),
+    (Bank::Khavarmianeh, "078", "Middle East Bank", "بانک خاورمیانه"),
+    (Bank::Noor, "080", "Noor Credit Institution", "موسسه اعتباری نور"),
+    (Bank::IranVenezuela, "095", "Iran-Venezuela Bi-National Bank", "بانک ایران و ونزوئلا"),
+];
+
+/// (bank, eight-character SWIFT/BIC code) of the banks connected to SWIFT.
+const BICS: [(Bank, &str); 21] = [
+    (Bank::Markazi, "BMJIIRTH"),
+    (Bank::SanatVaMadan, "BOIMIRTH"),
+    (Bank::Mellat, "BKMTIRTH"),
+    (Bank::Refah, "REFAIRTH"),
+    (Bank::Maskan, "BKMNIRTH"),
+    (Bank::Sepah, "SEPBIRTH"),
+    (Bank::Keshavarzi, "KESHIRTH"),
+    (Bank::Melli, "MELIIRTH"),
+    (Bank::Tejarat, "BTEJIRTH"),
+    (Bank::Saderat, "BSIRIRTH"),
+    (Bank::ToseeSaderat, "EDBIIRTH"),
+    (Bank::PostBank, "PBIRIRTH"),
+    (Bank::Karafarin, "KBIDIRTH"),
+    (Bank::Parsian, "BKPAIRTH"),
+    (Bank::EghtesadNovin, "BEGNIRTH"),
+    (Bank::Saman, "SABCIRTH"),
+    (Bank::Pasargad, "BKBPIRTH"),
+    (Bank::Sarmayeh, "SRMBIRTH"),
+    (Bank::Sina, "SINAIRTH"),
+    (Bank::Shahr, "CIYBIRTH"),
+    (Bank::Dey, "DAYBIRTH"),
+];
+
+impl Bank {
+    /// Looks up a bank by its three-digit code, as found in positions 5–7 of a Sheba.
+    pub fn from_code(code: &str) -> Option<Bank> {
+        BANKS.iter().find(|(_, c, _, _)| *c == code).map(|(bank, _, _, _)| *bank)
+    }
+
+    /// Looks up a bank by its SWIFT/BIC code, ignoring case and any three-character
+    /// branch suffix, e.g. `MELIIRTH` or `meliirthxxx`.
+    pub fn from_bic(bic: &str) -> Option<Bank> {
+        let bic = bic.trim();
+        if (bic.len() != 8 && bic.len() != 11) || !bic.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return None;
+        }
+        BICS.iter().find(|(_, b)| b.eq_ignore_ascii_case(&bic[..8])).map(|(bank, _)| *bank)
+    }
+
+    pub fn code(&self) -> &'static str {
+        self.entry().1
+    }
+
+    /// The eight-character SWIFT/BIC code, or `None` for banks not connected to SWIFT.
+    pub fn bic(&self) -> Option<&'static str> {
+        BICS.iter().find(|(bank, _)| bank == self).map(|(_, bic)| *bic)
+    }
+
+    pub fn english_name(&self) -> &'static str {
+        self.entry().2
+    }
+
+    pub fn persian_name(&self) -> &'static str {
+        self.entry().3
+    }
+
+    fn entry(&self) -> &'static (Bank, &'static str, &'static str, &'static str) {
+        BANKS.iter().find(|(bank, _, _, _)| bank == self).unwrap()
+    }
+}
+
+impl Display for Bank {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.english_name())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::bank::{Bank, BANKS, BICS};
+
+    #[test]
+    fn test_lookup_by_code() {
+        assert_eq!(Bank::from_code("017"), Some(Bank::Melli));
+        assert_eq!(Bank::from_code("056"), Some(Bank::Saman));
+        assert_eq!(Bank::from_code("999"), None);
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(Bank::Mellat.code(), "012");
+        assert_eq!(Bank::Mellat.english_name(), "Bank Mellat");
+        assert_eq!(Bank::Mellat.persian_name(), "بانک ملت");
+        assert_eq!(Bank::Mellat.to_string(), "Bank Mellat");
+    }
+
+    #[test]
+    fn test_table_round_trips() {
+        for (bank, code, _, _) in BANKS.iter() {
+            assert_eq!(Bank::from_code(code), Some(*bank));
+            assert_eq!(bank.code(), *code);
+        }
+        for (bank, bic) in BICS.iter() {
+            assert_eq!(Bank::from_bic(bic), Some(*bank));
+            assert_eq!(bank.bic(), Some(*bic));
+        }
+    }
+
+    #[test]
+    fn test_bic() {
+        assert_eq!(Bank::Melli.bic(), Some("MELIIRTH"));
+        assert_eq!(Bank::Resalat.bic(), None);
+        assert_eq!(Bank::from_bic(" bkmtirth "), Some(Bank::Mellat));
+        assert_eq!(Bank::from_bic("SABCIRTHXXX"), Some(Bank::Saman));
+        assert_eq!(Bank::from_bic("SABCIRTHX"), None);
+        assert_eq!(Bank::from_bic("DEUTDEFF"), None);
+        assert_eq!(Bank::from_bic("MELIIRTHفa"), None);
+    }
+}
@@ -0,0 +1,196 @@
+use alloc::string::String;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+use crate::bank::Bank;
+use crate::locale::LocalizedError;
+use crate::normalize::{is_separator, normalize_digit, to_persian_digits};
+
+/// Longest account number that fits the account field of a Sheba.
+const MAX_DIGITS: usize = 19;
+
+/// (bank, fewest digits, most digits) of the local account numbers issued by the major
+/// banks; dots and dashes between the groups, as in `207.8000.12345678.1`, are ignored.
+const FORMATS: [(Bank, usize, usize); 6] = [
+    (Bank::Melli, 13, 13),
+    (Bank::Mellat, 10, 10),
+    (Bank::Tejarat, 10, 10),
+    (Bank::Ayandeh, 13, 13),
+    (Bank::Pasargad, 14, 16),
+    (Bank::Saman, 12, 14),
+];
+
+/// Banks whose Sheba account field is an account type digit, zero padding and then the
+/// local account number, e.g. `0 00000 0101234567001` for a Melli account.
+const SHEBA_ENCODED: [Bank; 4] = [Bank::Melli, Bank::Mellat, Bank::Tejarat, Bank::Ayandeh];
+
+/// A local bank account number (شماره حساب), validated against the format of its bank.
+///
+/// Banks missing from the bundled format table accept any number of up to 19 digits,
+/// the size of the account field of a Sheba.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct BankAccount {
+    bank: Bank,
+    number: String,
+}
+
+impl BankAccount {
+    /// Validates `number` as an account at `bank`.
+    ///
+    /// Whitespace, dashes, zero-width characters, dots and slashes are removed first, as
+    /// banks print account numbers like `207.8000.12345678.1` or `0101234567/001`, and
+    /// Persian digits are accepted.
+    pub fn new(bank: Bank, number: &str) -> Result<Self, BankAccountError> {
+        let number: String = number.chars()
+            .filter(|c| !is_separator(*c) && !matches!(c, '.' | '/'))
+            .map(normalize_digit)
+            .collect();
+        if !number.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(BankAccountError::NonDigitCharacter);
+        }
+
+        let (min, max) = BankAccount::digits_range(bank);
+        if !(min..=max).contains(&number.len()) {
+            return Err(BankAccountError::InvalidLength { min, max, found: number.len() });
+        }
+        if number.bytes().all(|b| b == b'0') {
+            return Err(BankAccountError::AllZeros);
+        }
+        Ok(BankAccount { bank, number })
+    }
+
+    /// The fewest and most digits of an account number at `bank`.
+    pub fn digits_range(bank: Bank) -> (usize, usize) {
+        FORMATS.iter()
+            .find(|(b, _, _)| *b == bank)
+            .map_or((1, MAX_DIGITS), |(_, min, max)| (*min, *max))
+    }
+
+    /// Whether the bundled table knows the account number format of `bank`.
+    pub fn has_known_format(bank: Bank) -> bool {
+        FORMATS.iter().any(|(b, _, _)| *b == bank)
+    }
+
+    /// Splits the 19-digit account field of a Sheba at `bank` into the account type digit
+    /// and the local account number; `None` if the bank does not encode its accounts that
+    /// way or the padding is not zero.
+    pub(crate) fn from_sheba_field(bank: Bank, field: &str) -> Option<(u8, BankAccount)> {
+        if !SHEBA_ENCODED.contains(&bank) || field.len() != MAX_DIGITS {
+            return None;
+        }
+        let (len, _) = BankAccount::digits_range(bank);
+        let (head, number) = field.split_at(MAX_DIGITS - len);
+        if !head[1..].bytes().all(|b| b == b'0') {
+            return None;
+        }
+        let account_type = head.as_bytes()[0].checked_sub(b'0').filter(|d| *d < 10)?;
+        BankAccount::new(bank, number).ok().map(|account| (account_type, account))
+    }
+
+    pub fn bank(&self) -> Bank {
+        self.bank
+    }
+
+    /// The digits of the number, without separators.
+    pub fn as_str(&self) -> &str {
+        &self.number
+    }
+
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.number
+    }
+}
+
+impl AsRef<str> for BankAccount {
+    fn as_ref(&self) -> &str {
+        &self.number
+    }
+}
+
+impl Display for BankAccount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.number)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum BankAccountError {
+    InvalidLength { min: usize, max: usize, found: usize },
+    NonDigitCharacter,
+    AllZeros,
+}
+
+impl Error for BankAccountError {}
+
+impl Display for BankAccountError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BankAccountError::InvalidLength { min, max, found } if min == max => {
+                write!(f, "invalid bank account number: expected {} digits, got {}", min, found)
+            }
+            BankAccountError::InvalidLength { min, max, found } => {
+                write!(f, "invalid bank account number: expected {} to {} digits, got {}", min, max, found)
+            }
+            BankAccountError::NonDigitCharacter => {
+                write!(f, "invalid bank account number: contains non-digit characters")
+            }
+            BankAccountError::AllZeros => write!(f, "invalid bank account number: all digits are zero"),
+        }
+    }
+}
+
+impl LocalizedError for BankAccountError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "شماره حساب نامعتبر است: ")?;
+        match self {
+            BankAccountError::InvalidLength { min, max, found } if min == max => {
+                write!(f, "باید {} رقم باشد، {} رقم وارد شده", min, found)
+            }
+            BankAccountError::InvalidLength { min, max, found } => {
+                write!(f, "باید {} تا {} رقم باشد، {} رقم وارد شده", min, max, found)
+            }
+            BankAccountError::NonDigitCharacter => write!(f, "فقط باید شامل رقم باشد"),
+            BankAccountError::AllZeros => write!(f, "همه ارقام صفر است"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::bank::Bank;
+    use crate::bank_account::{BankAccount, BankAccountError};
+
+    #[test]
+    fn test_validate_bank_account() {
+        assert_eq!(BankAccount::new(Bank::Mellat, "۱۲۳۴۵۶۷۸۹۰").unwrap().as_str(), "1234567890");
+        assert_eq!(BankAccount::new(Bank::Melli, "0101234567001").unwrap().bank(), Bank::Melli);
+        assert_eq!(BankAccount::new(Bank::Pasargad, "207.8000.12345678.1").unwrap().as_str(), "2078000123456781");
+        assert_eq!(BankAccount::new(Bank::Saman, "849-800-1234567-1").unwrap().to_string(), "84980012345671");
+        assert_eq!(BankAccount::new(Bank::Melli, "0101234567/001").unwrap().as_str(), "0101234567001");
+        assert_eq!(BankAccount::new(Bank::Tejarat, " 12.34/56 7890").unwrap().as_str(), "1234567890");
+        assert!(BankAccount::new(Bank::Dey, "12345").is_ok());
+        assert!(!BankAccount::has_known_format(Bank::Dey));
+    }
+
+    #[test]
+    fn test_invalid_bank_account() {
+        assert_eq!(
+            BankAccount::new(Bank::Mellat, "123456789"),
+            Err(BankAccountError::InvalidLength { min: 10, max: 10, found: 9 })
+        );
+        assert_eq!(
+            BankAccount::new(Bank::Saman, "849-800-1").unwrap_err().to_string(),
+            "invalid bank account number: expected 12 to 14 digits, got 7"
+        );
+        assert_eq!(BankAccount::new(Bank::Tejarat, "123456789a"), Err(BankAccountError::NonDigitCharacter));
+        assert_eq!(BankAccount::new(Bank::Dey, "0000"), Err(BankAccountError::AllZeros));
+        assert_eq!(
+            BankAccount::new(Bank::Dey, "12345678901234567890").unwrap_err(),
+            BankAccountError::InvalidLength { min: 1, max: 19, found: 20 }
+        );
+    }
+}
@@ -0,0 +1,331 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Debug, Display, Formatter};
+use core::str::FromStr;
+
+use crate::bank::Bank;
+use crate::bin_table::BinTable;
+use crate::fake::Rng;
+use crate::locale::LocalizedError;
+use crate::normalize::{digits, digits_to_string, is_separator, normalize_digit, to_persian_digits, DigitsError};
+
+/// Shetab BIN (first six digits of the card number) allocations.
+pub(crate) const BINS: [(&str, Bank); 43] = [
+    ("603799", Bank::Melli),
+    ("589210", Bank::Sepah),
+    ("627648", Bank::ToseeSaderat),
+    ("207177", Bank::ToseeSaderat),
+    ("627961", Bank::SanatVaMadan),
+    ("603770", Bank::Keshavarzi),
+    ("639217", Bank::Keshavarzi),
+    ("628023", Bank::Maskan),
+    ("627760", Bank::PostBank),
+    ("502908", Bank::ToseeTaavon),
+    ("627412", Bank::EghtesadNovin),
+    ("622106", Bank::Parsian),
+    ("627884", Bank::Parsian),
+    ("639194", Bank::Parsian),
+    ("502229", Bank::Pasargad),
+    ("639347", Bank::Pasargad),
+    ("627488", Bank::Karafarin),
+    ("502910", Bank::Karafarin),
+    ("621986", Bank::Saman),
+    ("639346", Bank::Sina),
+    ("639607", Bank::Sarmayeh),
+    ("502806", Bank::Shahr),
+    ("504706", Bank::Shahr),
+    ("502938", Bank::Dey),
+    ("603769", Bank::Saderat),
+    ("610433", Bank::Mellat),
+    ("991975", Bank::Mellat),
+    ("627353", Bank::Tejarat),
+    ("585983", Bank::Tejarat),
+    ("589463", Bank::Refah),
+    ("627381", Bank::Ansar),
+    ("505785", Bank::IranZamin),
+    ("636214", Bank::Ayandeh),
+    ("636949", Bank::Hekmat),
+    ("505416", Bank::Gardeshgari),
+    ("504172", Bank::Resalat),
+    ("606373", Bank::MehrIran),
+    ("507677", Bank::Noor),
+    ("628157", Bank::ToseeCredit),
+    ("505801", Bank::Kosar),
+    ("606256", Bank::Melal),
+    ("585947", Bank::Khavarmianeh),
+    ("636795", Bank::Markazi),
+];
+
+/// 16-digit Iranian (Shetab) bank card number.
+///
+/// `Debug` prints the masked form so card numbers don't leak into logs or panic messages.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct BankCard(String);
+
+impl TryFrom<&str> for BankCard {
+    type Error = BankCardError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let digits: [u32; 16] = digits(value.chars().filter(|c| !is_separator(*c))).map_err(|err| match err {
+            DigitsError::Length(len) => BankCardError::InvalidLength(len),
+            DigitsError::NonDigit => BankCardError::NonDigitCharacter,
+        })?;
+
+        if !luhn(&digits) {
+            return Err(BankCardError::InvalidChecksum);
+        }
+        Ok(BankCard(digits_to_string(&digits)))
+    }
+}
+
+pub(crate) fn luhn(digits: &[u32]) -> bool {
+    let sum: u32 = digits.iter().rev().enumerate()
+        .map(|(i, d)| match (i % 2, d * 2) {
+            (0, _) => *d,
+            (_, doubled) if doubled > 9 => doubled - 9,
+            (_, doubled) => doubled,
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+/// Detects the issuing bank from the leading digits of a card number, which may be
+/// incomplete (e.g. while the user is still typing). At least six digits are needed.
+pub fn issuer_from_prefix(input: &str) -> Option<Bank> {
+    let bin = leading_bin(input)?;
+    BINS.iter().find(|(b, _)| b.as_bytes() == bin).map(|(_, bank)| *bank)
+}
+
+/// The first six digits of a possibly incomplete card number.
+pub(crate) fn leading_bin(input: &str) -> Option<[u8; 6]> {
+    let mut bin = [0; 6];
+    let mut chars = input.chars().filter(|c| !is_separator(*c)).map(normalize_digit);
+    for digit in bin.iter_mut() {
+        match chars.next() {
+            Some(c) if c.is_ascii_digit() => *digit = c as u8,
+            _ => return None,
+        }
+    }
+    Some(bin)
+}
+
+impl TryFrom<String> for BankCard {
+    type Error = BankCardError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        BankCard::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for BankCard {
+    type Error = BankCardError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        BankCard::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<u64> for BankCard {
+    type Error = BankCardError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        BankCard::try_from(value.to_string().as_str())
+    }
+}
+
+impl FromStr for BankCard {
+    type Err = BankCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BankCard::try_from(s)
+    }
+}
+
+impl BankCard {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The sixteen digits written with Persian digits, without grouping.
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    /// Groups of four digits separated by dashes, e.g. `6219-8610-3452-9007`.
+    pub fn formatted(&self) -> String {
+        group(&self.0)
+    }
+
+    /// Formatted form with everything but the BIN and the last four digits hidden,
+    /// e.g. `6219-86**-****-9007`.
+    pub fn masked(&self) -> String {
+        let masked: String = self.0.chars().enumerate()
+            .map(|(i, c)| if (6..12).contains(&i) { '*' } else { c })
+            .collect();
+        group(&masked)
+    }
+
+    /// Generates a random Luhn-valid card number with a BIN from the bundled table.
+    pub fn random(rng: &mut Rng) -> Self {
+        random_with_bin(rng.choose(&BINS).0, rng)
+    }
+
+    /// Generates a random Luhn-valid card number issued by `bank`, or `None` if the
+    /// bundled table has no BIN for it.
+    pub fn random_for_bank(bank: Bank, rng: &mut Rng) -> Option<Self> {
+        let count = BINS.iter().filter(|(_, b)| *b == bank).count();
+        if count == 0 {
+            return None;
+        }
+
+        let (bin, _) = BINS.iter().filter(|(_, b)| *b == bank).nth(rng.below(count as u64) as usize)?;
+        Some(random_with_bin(bin, rng))
+    }
+
+    /// The issuing bank according to the bundled BIN table, if the BIN is known.
+    pub fn issuer(&self) -> Option<Bank> {
+        issuer_from_prefix(&self.0)
+    }
+
+    /// Like [`BankCard::issuer`], using a table loaded at runtime instead of the bundled one.
+    pub fn issuer_in(&self, table: &BinTable) -> Option<Bank> {
+        table.issuer(&self.0)
+    }
+}
+
+fn random_with_bin(bin: &str, rng: &mut Rng) -> BankCard {
+    let body = rng.digits_after(bin, 15);
+    (0..10)
+        .map(|check| format!("{}{}", body, check))
+        .find_map(|number| BankCard::try_from(number).ok())
+        .unwrap()
+}
+
+fn group(digits: &str) -> String {
+    let chars: Vec<char> = digits.chars().collect();
+    chars.chunks(4).map(|c| c.iter().collect::<String>()).collect::<Vec<_>>().join("-")
+}
+
+impl AsRef<str> for BankCard {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for BankCard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Debug for BankCard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("BankCard").field(&self.masked()).finish()
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum BankCardError {
+    InvalidLength(usize),
+    NonDigitCharacter,
+    InvalidChecksum,
+}
+
+impl Error for BankCardError {}
+
+impl Display for BankCardError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BankCardError::InvalidLength(len) => {
+                write!(f, "invalid bank card number: expected 16 digits, got {}", len)
+            }
+            BankCardError::NonDigitCharacter => write!(f, "invalid bank card number: contains non-digit characters"),
+            BankCardError::InvalidChecksum => write!(f, "invalid bank card number: luhn checksum mismatch"),
+        }
+    }
+}
+
+impl LocalizedError for BankCardError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "شماره کارت نامعتبر است: ")?;
+        match self {
+            BankCardError::InvalidLength(len) => write!(f, "باید 16 رقم باشد، {} رقم وارد شده", len),
+            BankCardError::NonDigitCharacter => write!(f, "فقط باید شامل رقم باشد"),
+            BankCardError::InvalidChecksum => write!(f, "رقم کنترل مطابقت ندارد"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::bank::Bank;
+    use crate::bank_card::{issuer_from_prefix, BankCard, BankCardError};
+    use crate::fake::Rng;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_random_cards_are_valid() {
+        let mut rng = Rng::new(1);
+        for _ in 0..100 {
+            let card = BankCard::random(&mut rng);
+            assert_eq!(BankCard::try_from(card.as_str()), Ok(card.clone()));
+            assert!(card.issuer().is_some());
+
+            let card = BankCard::random_for_bank(Bank::Parsian, &mut rng).unwrap();
+            assert_eq!(BankCard::try_from(card.as_str()).unwrap().issuer(), Some(Bank::Parsian));
+        }
+        assert_eq!(BankCard::random_for_bank(Bank::IranVenezuela, &mut rng), None);
+    }
+
+    #[test]
+    fn test_validate_bank_card() {
+        assert!(BankCard::try_from("6037991234567893").is_ok());
+        assert!(BankCard::try_from("6219861034529007").is_ok());
+        assert!(BankCard::try_from(6104330000000003u64).is_ok());
+        assert!("5022298765432102".parse::<BankCard>().is_ok());
+    }
+
+    #[test]
+    fn test_normalization() {
+        let expected = "6219861034529007";
+        assert_eq!(BankCard::try_from("6219 8610 3452 9007").unwrap().as_str(), expected);
+        assert_eq!(BankCard::try_from("6219-8610-3452-9007").unwrap().as_str(), expected);
+        assert_eq!(BankCard::try_from("۶۲۱۹۸۶۱۰۳۴۵۲۹۰۰۷").unwrap().to_string(), expected);
+    }
+
+    #[test]
+    fn test_issuer() {
+        assert_eq!(BankCard::try_from("6037991234567893").unwrap().issuer(), Some(Bank::Melli));
+        assert_eq!(BankCard::try_from("6219861034529007").unwrap().issuer(), Some(Bank::Saman));
+        assert_eq!(BankCard::try_from("1234567890123452").unwrap().issuer(), None);
+    }
+
+    #[test]
+    fn test_issuer_from_partial_input() {
+        assert_eq!(issuer_from_prefix("6104 33"), Some(Bank::Mellat));
+        assert_eq!(issuer_from_prefix("۶۲۱۹-۸۶۱"), Some(Bank::Saman));
+        assert_eq!(issuer_from_prefix("6104"), None);
+    }
+
+    #[test]
+    fn test_formatting_and_masking() {
+        let card = BankCard::try_from("6219861034529007").unwrap();
+        assert_eq!(card.formatted(), "6219-8610-3452-9007");
+        assert_eq!(card.masked(), "6219-86**-****-9007");
+        assert_eq!(format!("{:?}", card), "BankCard(\"6219-86**-****-9007\")");
+    }
+
+    #[test]
+    fn test_invalid_bank_card() {
+        assert_eq!(BankCard::try_from("621986103452900"), Err(BankCardError::InvalidLength(15)));
+        assert_eq!(BankCard::try_from("621986103452900a"), Err(BankCardError::NonDigitCharacter));
+        assert_eq!(BankCard::try_from("6219861034529008"), Err(BankCardError::InvalidChecksum));
+    }
+}
@@ -0,0 +1,449 @@
+use core::convert::TryFrom;
+use core::fmt::{Display, Formatter};
+use core::hash::{Hash, Hasher};
+use std::collections::hash_map::{DefaultHasher, Entry, HashMap};
+use std::collections::HashSet;
+use std::io::{self, BufRead};
+
+use crate::national_id::{NationalId, NationalIdError};
+
+/// Validates many national ids at once, with results in input order.
+///
+/// Canonical input (exactly ten ASCII digits) goes through an unrolled checksum that
+/// the compiler can vectorize; anything else, and every failure, falls back to
+/// `NationalId::try_from` so results and errors are identical to the per-item path.
+pub fn validate_batch(inputs: &[&str]) -> Vec<Result<NationalId, NationalIdError>> {
+    inputs.iter().map(|input| validate_one(input)).collect()
+}
+
+/// Like [`validate_batch`], but splits the input across all available cores.
+pub fn par_validate_batch(inputs: &[&str]) -> Vec<Result<NationalId, NationalIdError>> {
+    let threads = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    let chunk_size = inputs.len().div_ceil(threads).max(1);
+    if threads == 1 || inputs.len() <= chunk_size {
+        return validate_batch(inputs);
+    }
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = inputs.chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || validate_batch(chunk)))
+            .collect();
+        handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect()
+    })
+}
+
+/// Outcome of [`NationalId::validate_all`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BatchReport {
+    /// Number of inputs checked.
+    pub total: usize,
+    /// Valid ids with the index of the input they came from.
+    pub valid: Vec<(usize, NationalId)>,
+    pub failures: Vec<Failure>,
+}
+
+impl BatchReport {
+    pub fn is_all_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// A rejected input: its zero-based position in the batch and why it failed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure {
+    pub index: usize,
+    pub error: NationalIdError,
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "input {}: {}", self.index, self.error)
+    }
+}
+
+pub(crate) fn report<'a, I: IntoIterator<Item = &'a str>>(inputs: I) -> BatchReport {
+    let mut report = BatchReport::default();
+    for (index, input) in inputs.into_iter().enumerate() {
+        match validate_one(input) {
+            Ok(id) => report.valid.push((index, id)),
+            Err(error) => report.failures.push(Failure { index, error }),
+        }
+        report.total += 1;
+    }
+    report
+}
+
+/// Lazily validates newline-delimited ids read from `reader`, one line at a time.
+///
+/// A single line buffer is reused, so memory stays constant regardless of input size.
+pub fn validate_lines<R: BufRead>(reader: R) -> ValidateLines<R> {
+    ValidateLines { reader, line: String::new() }
+}
+
+/// Iterator returned by [`validate_lines`].
+pub struct ValidateLines<R> {
+    reader: R,
+    line: String,
+}
+
+impl<R: BufRead> Iterator for ValidateLines<R> {
+    type Item = io::Result<Result<NationalId, NationalIdError>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.line.clear();
+        match self.reader.read_line(&mut self.line) {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(validate_one(self.line.trim_end_matches(&['\r', '\n'][..])))),
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+/// Adds national id validation adapters to any iterator of strings.
+pub trait NationalIdIteratorExt: Iterator + Sized
+where
+    Self::Item: AsRef<str>,
+{
+    /// Maps every item to its validation result.
+    fn validate_national_ids(self) -> ValidateNationalIds<Self> {
+        ValidateNationalIds(self)
+    }
+
+    /// Yields only the items that are valid national ids.
+    fn filter_valid_ids(self) -> FilterValidIds<Self> {
+        FilterValidIds(self)
+    }
+
+    /// Classifies every item as a new id, a duplicate of an earlier one or invalid, for
+    /// data-quality audits of exports; see [`AuditNationalIds`].
+    fn audit_national_ids(self) -> AuditNationalIds<Self> {
+        AuditNationalIds {
+            inputs: self,
+            index: 0,
+            first: HashMap::new(),
+            spellings: HashSet::new(),
+            summary: AuditSummary::default(),
+        }
+    }
+}
+
+impl<I> NationalIdIteratorExt for I
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+}
+
+/// Iterator returned by [`NationalIdIteratorExt::validate_national_ids`].
+pub struct ValidateNationalIds<I>(I);
+
+impl<I> Iterator for ValidateNationalIds<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = Result<NationalId, NationalIdError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|item| validate_one(item.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// Iterator returned by [`NationalIdIteratorExt::filter_valid_ids`].
+pub struct FilterValidIds<I>(I);
+
+impl<I> Iterator for FilterValidIds<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = NationalId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.by_ref().find_map(|item| validate_one(item.as_ref()).ok())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.0.size_hint().1)
+    }
+}
+
+/// Iterator returned by [`NationalIdIteratorExt::audit_national_ids`].
+///
+/// Items are parsed like [`NationalId::parse_lenient`] and compared by the id they
+/// normalize to, so `40010007`, `۰۰۴۰۰۱۰۰۰۷` and `004-001000-7` are all duplicates of
+/// `0040010007`. An item is an exact duplicate if any earlier item had the same raw text.
+///
+/// Raw inputs are not retained, but memory is O(distinct ids + distinct spellings): each
+/// distinct valid id keeps its packed body and first index, and each distinct spelling of
+/// it a 64-bit hash of the raw text, roughly 40 bytes per entry with map overhead.
+/// Invalid entries and exact duplicates add nothing.
+pub struct AuditNationalIds<I> {
+    inputs: I,
+    index: usize,
+    first: HashMap<u32, usize>,
+    spellings: HashSet<(u32, u64)>,
+    summary: AuditSummary,
+}
+
+impl<I> AuditNationalIds<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    /// Counts of the items audited so far.
+    pub fn summary(&self) -> &AuditSummary {
+        &self.summary
+    }
+
+    /// Audits the remaining items, returning the counts for the whole input.
+    pub fn finish(mut self) -> AuditSummary {
+        self.by_ref().for_each(drop);
+        self.summary
+    }
+}
+
+impl<I> Iterator for AuditNationalIds<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = AuditEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.inputs.next()?;
+        let raw = item.as_ref();
+        let index = self.index;
+        self.index += 1;
+        self.summary.total += 1;
+
+        let id = match audit_one(raw) {
+            Ok(id) => id,
+            Err(error) => {
+                self.summary.invalid += 1;
+                return Some(AuditEntry::Invalid { index, error });
+            }
+        };
+        let new_spelling = self.spellings.insert((id.body(), raw_hash(raw)));
+        match self.first.entry(id.body()) {
+            Entry::Vacant(entry) => {
+                entry.insert(index);
+                self.summary.unique += 1;
+                Some(AuditEntry::Unique { index, id })
+            }
+            Entry::Occupied(entry) => {
+                let first = *entry.get();
+                if !new_spelling {
+                    self.summary.exact_duplicates += 1;
+                    Some(AuditEntry::ExactDuplicate { index, id, first })
+                } else {
+                    self.summary.normalized_duplicates += 1;
+                    Some(AuditEntry::NormalizedDuplicate { index, id, first })
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inputs.size_hint()
+    }
+}
+
+fn audit_one(input: &str) -> Result<NationalId, NationalIdError> {
+    if input.len() == 10 && fast_check(input.as_bytes()) {
+        return Ok(NationalId::new_unchecked(input));
+    }
+    NationalId::parse_lenient(input)
+}
+
+fn raw_hash(raw: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    raw.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// How one item of an audit was classified; indices are zero-based positions in the input.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuditEntry {
+    /// The first occurrence of a valid id.
+    Unique { index: usize, id: NationalId },
+    /// Same raw text as an earlier item; `first` is the index where the id first appeared.
+    ExactDuplicate { index: usize, id: NationalId, first: usize },
+    /// Written differently from every earlier occurrence of the id, which first appeared
+    /// at index `first`, but normalizes to the same id, e.g. without leading zeros, with
+    /// persian digits or with dashes.
+    NormalizedDuplicate { index: usize, id: NationalId, first: usize },
+    Invalid { index: usize, error: NationalIdError },
+}
+
+impl AuditEntry {
+    pub fn index(&self) -> usize {
+        match self {
+            AuditEntry::Unique { index, .. }
+            | AuditEntry::ExactDuplicate { index, .. }
+            | AuditEntry::NormalizedDuplicate { index, .. }
+            | AuditEntry::Invalid { index, .. } => *index,
+        }
+    }
+
+    pub fn is_unique(&self) -> bool {
+        matches!(self, AuditEntry::Unique { .. })
+    }
+}
+
+impl Display for AuditEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AuditEntry::Unique { index, id } => write!(f, "input {}: {}", index, id),
+            AuditEntry::ExactDuplicate { index, id, first } => {
+                write!(f, "input {}: duplicate of input {} ({})", index, first, id)
+            }
+            AuditEntry::NormalizedDuplicate { index, id, first } => {
+                write!(f, "input {}: same id as input {} after normalization ({})", index, first, id)
+            }
+            AuditEntry::Invalid { index, error } => write!(f, "input {}: {}", index, error),
+        }
+    }
+}
+
+/// Counts from an audit; `unique + exact_duplicates + normalized_duplicates + invalid`
+/// always equals `total`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AuditSummary {
+    pub total: usize,
+    pub unique: usize,
+    pub exact_duplicates: usize,
+    pub normalized_duplicates: usize,
+    pub invalid: usize,
+}
+
+impl AuditSummary {
+    pub fn is_clean(&self) -> bool {
+        self.unique == self.total
+    }
+}
+
+pub(crate) fn validate_one(input: &str) -> Result<NationalId, NationalIdError> {
+    if input.len() == 10 && fast_check(input.as_bytes()) {
+        return Ok(NationalId::new_unchecked(input));
+    }
+    NationalId::try_from(input)
+}
+
+#[inline]
+fn fast_check(b: &[u8]) -> bool {
+    let d: [u32; 10] = core::array::from_fn(|i| b[i].wrapping_sub(b'0') as u32);
+    if d.iter().any(|x| *x > 9) {
+        return false;
+    }
+
+    let sum = d[0] * 10 + d[1] * 9 + d[2] * 8 + d[3] * 7 + d[4] * 6
+        + d[5] * 5 + d[6] * 4 + d[7] * 3 + d[8] * 2;
+    let rem = sum % 11;
+    let expected = if rem < 2 { rem } else { 11 - rem };
+    sum != 0 && expected == d[9]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::batch::{par_validate_batch, validate_batch, validate_lines, AuditEntry, AuditSummary, Failure, NationalIdIteratorExt};
+    use crate::national_id::NationalIdError;
+    use crate::fake;
+    use crate::national_id::NationalId;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_batch_matches_per_item_validation() {
+        let inputs = ["0040010007", "40010007", "۰۸۱۴۶۵۹۴۳۸", "0040010008", "0000000000", "004001000a", "", "1234567890123"];
+        let expected: Vec<_> = inputs.iter().map(|i| NationalId::try_from(*i)).collect();
+        assert_eq!(validate_batch(&inputs), expected);
+    }
+
+    #[test]
+    fn test_batch_of_generated_ids() {
+        let ids: Vec<String> = fake::national_ids(5, 1000).iter().map(|id| id.to_string()).collect();
+        let inputs: Vec<&str> = ids.iter().map(|s| s.as_str()).collect();
+        assert!(validate_batch(&inputs).iter().all(|r| r.is_ok()));
+    }
+
+    #[test]
+    fn test_parallel_batch_preserves_order() {
+        let mut ids: Vec<String> = fake::national_ids(6, 10_000).iter().map(|id| id.to_string()).collect();
+        ids[1234].replace_range(9.., "x");
+        let inputs: Vec<&str> = ids.iter().map(|s| s.as_str()).collect();
+        assert_eq!(par_validate_batch(&inputs), validate_batch(&inputs));
+        assert!(par_validate_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_validate_all_reports_failures_by_index() {
+        let report = NationalId::validate_all(vec!["0040010007", "0040010008", "0814659438", "x"]);
+        assert_eq!(report.total, 4);
+        assert_eq!(report.valid.iter().map(|(i, _)| *i).collect::<Vec<_>>(), vec![0, 2]);
+        assert_eq!(report.failures, vec![
+            Failure { index: 1, error: NationalIdError::InvalidCheckDigit { expected: 7, found: 8 } },
+            Failure { index: 3, error: NationalIdError::NonDigitCharacter },
+        ]);
+        assert!(!report.is_all_valid());
+        assert_eq!(report.failures[0].to_string(), "input 1: invalid iranian national id number: check digit should be 7, found 8");
+    }
+
+    #[test]
+    fn test_validate_lines() {
+        let input = "0040010007\r\n0040010008\n\n0814659438";
+        let results: Vec<_> = validate_lines(input.as_bytes()).map(|r| r.unwrap()).collect();
+        assert_eq!(results, vec![
+            NationalId::try_from("0040010007"),
+            Err(NationalIdError::InvalidCheckDigit { expected: 7, found: 8 }),
+            Err(NationalIdError::AllZeros),
+            NationalId::try_from("0814659438"),
+        ]);
+    }
+
+    #[test]
+    fn test_iterator_adapters() {
+        let inputs = vec!["0040010007", "0040010008", "0814659438"];
+        let results: Vec<_> = inputs.iter().validate_national_ids().collect();
+        assert_eq!(results, inputs.iter().map(|i| NationalId::try_from(*i)).collect::<Vec<_>>());
+
+        let valid: Vec<String> = inputs.into_iter().map(String::from).filter_valid_ids().map(|id| id.to_string()).collect();
+        assert_eq!(valid, vec!["0040010007", "0814659438"]);
+    }
+
+    #[test]
+    fn test_audit_national_ids() {
+        let inputs = ["0040010007", "40010007", "0040010007", "0040010008", "۰۰۴۰۰۱۰۰۰۷", "0814659438"];
+        let id = NationalId::try_from("0040010007").unwrap();
+        let mut audit = inputs.iter().audit_national_ids();
+        let entries: Vec<AuditEntry> = audit.by_ref().take(5).collect();
+        assert_eq!(entries, vec![
+            AuditEntry::Unique { index: 0, id },
+            AuditEntry::NormalizedDuplicate { index: 1, id, first: 0 },
+            AuditEntry::ExactDuplicate { index: 2, id, first: 0 },
+            AuditEntry::Invalid { index: 3, error: NationalIdError::InvalidCheckDigit { expected: 7, found: 8 } },
+            AuditEntry::NormalizedDuplicate { index: 4, id, first: 0 },
+        ]);
+        assert_eq!(entries[1].to_string(), "input 1: same id as input 0 after normalization (0040010007)");
+        assert_eq!(audit.summary().total, 5);
+        assert_eq!(audit.finish(), AuditSummary {
+            total: 6,
+            unique: 2,
+            exact_duplicates: 1,
+            normalized_duplicates: 2,
+            invalid: 1,
+        });
+
+        let audit: Vec<AuditEntry> = ["40010007", "0040010007", "0040010007", "004-001000-7"].iter().audit_national_ids().collect();
+        assert_eq!(audit, vec![
+            AuditEntry::Unique { index: 0, id },
+            AuditEntry::NormalizedDuplicate { index: 1, id, first: 0 },
+            AuditEntry::ExactDuplicate { index: 2, id, first: 0 },
+            AuditEntry::NormalizedDuplicate { index: 3, id, first: 0 },
+        ]);
+        assert!(fake::national_ids(3, 100).iter().map(|id| id.to_string()).audit_national_ids().finish().is_clean());
+    }
+}
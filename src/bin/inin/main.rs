@@ -0,0 +1,398 @@
+mod records;
+
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::process;
+
+use inin::csv::{escape as csv_escape, fields as csv_fields};
+use inin::fake::Rng;
+use inin::{NationalId, Province};
+
+use crate::records::{json_escape, json_field};
+
+const USAGE: &str = "usage:
+    inin validate [--format plain|csv|json] [--quiet] <code>...
+    inin validate [--input <file>|-] [--input-format csv|jsonl] [--column <name>] [--output <file>]
+                  [--format plain|csv|json] [--quiet]
+    inin generate [--count <n>] [--seed <n>] [--province <name> | --distribution <uniform|population>]
+    inin info <code>
+
+exit status: 0 if every input is valid, 1 if some input is invalid, 2 on usage or i/o errors";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let code = match run(&args, &mut stdin.lock(), &mut stdout.lock()) {
+        Ok(true) => 0,
+        Ok(false) => 1,
+        Err(message) => {
+            eprintln!("inin: {}\n{}", message, USAGE);
+            2
+        }
+    };
+    process::exit(code);
+}
+
+/// Runs one command, returning whether every input was valid or a usage error.
+///
+/// `main` maps these to the documented exit status: 0, 1 and 2 respectively.
+fn run(args: &[String], stdin: &mut dyn BufRead, out: &mut dyn Write) -> Result<bool, String> {
+    let (command, rest) = args.split_first().ok_or("missing command")?;
+    match command.as_str() {
+        "validate" => validate(rest, stdin, out),
+        "generate" => generate(rest, out),
+        "info" => info(rest, out),
+        "help" | "--help" | "-h" => {
+            writeln!(out, "{}", USAGE).map_err(|e| e.to_string())?;
+            Ok(true)
+        }
+        other => Err(format!("unknown command `{}`", other)),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Csv,
+    JsonLines,
+}
+
+impl Format {
+    /// JSON Lines for `.jsonl`/`.ndjson` files and stdin, CSV otherwise.
+    fn detect(input: &str) -> Format {
+        match Path::new(input).extension().and_then(|e| e.to_str()) {
+            _ if input == "-" => Format::JsonLines,
+            Some("jsonl") | Some("ndjson") => Format::JsonLines,
+            _ => Format::Csv,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OutputFormat {
+    Plain,
+    Csv,
+    Json,
+}
+
+/// Writes one result per validated input in the chosen output format.
+struct Report<'a> {
+    out: &'a mut dyn Write,
+    format: OutputFormat,
+    column: &'a str,
+    quiet: bool,
+    all_valid: bool,
+}
+
+impl Report<'_> {
+    fn header(&mut self) -> Result<(), String> {
+        if self.quiet || self.format != OutputFormat::Csv {
+            return Ok(());
+        }
+        writeln!(self.out, "line,{},status,error", csv_escape(self.column)).map_err(|e| e.to_string())
+    }
+
+    fn row(&mut self, line: usize, input: &str, result: Result<NationalId, String>) -> Result<(), String> {
+        self.all_valid &= result.is_ok();
+        if self.quiet {
+            return Ok(());
+        }
+
+        let text = match (self.format, &result) {
+            (OutputFormat::Plain, Ok(id)) => format!("{}: valid ({})", input, id),
+            (OutputFormat::Plain, Err(e)) => format!("{}: {}", input, e),
+            (OutputFormat::Csv, Ok(_)) => format!("{},{},valid,", line, csv_escape(input)),
+            (OutputFormat::Csv, Err(e)) => format!("{},{},invalid,{}", line, csv_escape(input), csv_escape(e)),
+            (OutputFormat::Json, Ok(id)) => format!(
+                "{{\"line\":{},\"input\":\"{}\",\"valid\":true,\"normalized\":\"{}\"}}",
+                line, json_escape(input), id
+            ),
+            (OutputFormat::Json, Err(e)) => format!(
+                "{{\"line\":{},\"input\":\"{}\",\"valid\":false,\"error\":\"{}\"}}",
+                line, json_escape(input), json_escape(e)
+            ),
+        };
+        writeln!(self.out, "{}", text).map_err(|e| e.to_string())
+    }
+}
+
+fn validate(args: &[String], stdin: &mut dyn BufRead, out: &mut dyn Write) -> Result<bool, String> {
+    let mut codes = Vec::new();
+    let mut input = None;
+    let mut format = None;
+    let mut output_format = None;
+    let mut column = "national_id".to_string();
+    let mut output = None;
+    let mut quiet = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with('-') || arg == "-" {
+            codes.push(arg);
+            continue;
+        }
+        if arg == "--quiet" || arg == "-q" {
+            quiet = true;
+            continue;
+        }
+        let value = args.next().ok_or_else(|| format!("{} needs a value", arg))?;
+        match arg.as_str() {
+            "--input" => input = Some(value.as_str()),
+            "--input-format" => format = Some(match value.as_str() {
+                "csv" => Format::Csv,
+                "jsonl" => Format::JsonLines,
+                other => return Err(format!("unknown input format `{}`", other)),
+            }),
+            "--format" => output_format = Some(match value.as_str() {
+                "plain" => OutputFormat::Plain,
+                "csv" => OutputFormat::Csv,
+                "json" => OutputFormat::Json,
+                other => return Err(format!("unknown output format `{}`", other)),
+            }),
+            "--column" => column = value.clone(),
+            "--output" => output = Some(value.as_str()),
+            other => return Err(format!("unknown option `{}`", other)),
+        }
+    }
+
+    if !codes.is_empty() {
+        if input.is_some() {
+            return Err("pass either codes or --input, not both".to_string());
+        }
+        let mut report = Report { out, format: output_format.unwrap_or(OutputFormat::Plain), column: &column, quiet, all_valid: true };
+        report.header()?;
+        for (i, code) in codes.iter().enumerate() {
+            report.row(i + 1, code, NationalId::parse_lenient(code).map_err(|e| e.to_string()))?;
+        }
+        return Ok(report.all_valid);
+    }
+
+    let input = input.unwrap_or("-");
+    let format = format.unwrap_or_else(|| Format::detect(input));
+    let mut file;
+    let reader: &mut dyn BufRead = if input == "-" {
+        stdin
+    } else {
+        file = BufReader::new(File::open(input).map_err(|e| format!("cannot open {}: {}", input, e))?);
+        &mut file
+    };
+
+    let mut writer;
+    let out: &mut dyn Write = match output {
+        Some(path) => {
+            writer = BufWriter::new(File::create(path).map_err(|e| format!("cannot create {}: {}", path, e))?);
+            &mut writer
+        }
+        None => out,
+    };
+    let mut report = Report { out, format: output_format.unwrap_or(OutputFormat::Csv), column: &column, quiet, all_valid: true };
+    validate_records(reader, format, &mut report)?;
+    report.out.flush().map_err(|e| e.to_string())?;
+    Ok(report.all_valid)
+}
+
+/// Validates the `column` field of every CSV row or JSON Lines record, reporting each
+/// record under its line number in the input.
+fn validate_records(reader: &mut dyn BufRead, format: Format, report: &mut Report<'_>) -> Result<(), String> {
+    let column = report.column;
+    let mut lines = reader.lines().enumerate();
+    let index = match format {
+        Format::Csv => {
+            let header = match lines.next() {
+                Some((_, header)) => header.map_err(|e| e.to_string())?,
+                None => return Err("input is empty".to_string()),
+            };
+            let index = csv_fields(&header).iter().position(|field| field.trim() == column);
+            Some(index.ok_or_else(|| format!("no column `{}` in the csv header", column))?)
+        }
+        Format::JsonLines => None,
+    };
+
+    report.header()?;
+    for (n, line) in lines {
+        let line = line.map_err(|e| e.to_string())?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let value = match index {
+            Some(index) => csv_fields(&line).get(index).cloned().ok_or_else(|| format!("missing column `{}`", column)),
+            None => json_field(&line, column)
+                .map_err(|e| format!("malformed json: {}", e))
+                .and_then(|value| value.ok_or_else(|| format!("missing field `{}`", column))),
+        };
+        match value {
+            Ok(value) => report.row(n + 1, &value, NationalId::parse_lenient(&value).map_err(|e| e.to_string()))?,
+            Err(e) => report.row(n + 1, "", Err(e))?,
+        }
+    }
+    Ok(())
+}
+
+fn generate(args: &[String], out: &mut dyn Write) -> Result<bool, String> {
+    let mut count = 1;
+    let mut rng = Rng::from_entropy();
+    let mut province = None;
+    let mut by_population = false;
+
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| format!("{} needs a value", flag))?;
+        match flag.as_str() {
+            "--count" | "-n" => count = value.parse().map_err(|_| format!("invalid count `{}`", value))?,
+            "--seed" => rng = Rng::new(value.parse().map_err(|_| format!("invalid seed `{}`", value))?),
+            "--province" => province = Some(value.parse::<Province>().map_err(|e| format!("{} `{}`", e, value))?),
+            "--distribution" => by_population = match value.as_str() {
+                "uniform" => false,
+                "population" => true,
+                _ => return Err(format!("unknown distribution `{}`", value)),
+            },
+            other => return Err(format!("unknown option `{}`", other)),
+        }
+    }
+
+    for _ in 0..count {
+        let id = match province {
+            Some(province) => NationalId::random_for_province(province, &mut rng)
+                .ok_or_else(|| format!("no known prefix for {}", province))?,
+            None if by_population => NationalId::random_by_population(&mut rng),
+            None => NationalId::random(&mut rng),
+        };
+        writeln!(out, "{}", id).map_err(|e| e.to_string())?;
+    }
+    Ok(true)
+}
+
+fn info(args: &[String], out: &mut dyn Write) -> Result<bool, String> {
+    let code = match args {
+        [code] => code,
+        _ => return Err("info needs exactly one code".to_string()),
+    };
+
+    let id = match NationalId::parse_lenient(code) {
+        Ok(id) => id,
+        Err(e) => {
+            writeln!(out, "{}: {}", code, e).map_err(|e| e.to_string())?;
+            return Ok(false);
+        }
+    };
+
+    let location = match id.issuance_location() {
+        Some(location) => format!("{}, {}", location.city, location.province),
+        None => "unknown".to_string(),
+    };
+    write!(out, "normalized: {}\nissued in: {}\n", id, location).map_err(|e| e.to_string())?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+
+    fn run_with_stdin(args: &[&str], stdin: &str) -> (Result<bool, String>, String) {
+        let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        let mut out = Vec::new();
+        let result = run(&args, &mut stdin.as_bytes(), &mut out);
+        (result, String::from_utf8(out).unwrap())
+    }
+
+    fn run_to_string(args: &[&str]) -> (Result<bool, String>, String) {
+        run_with_stdin(args, "")
+    }
+
+    #[test]
+    fn test_validate() {
+        let (result, out) = run_to_string(&["validate", "004-001000-7", "0040010008"]);
+        assert_eq!(result, Ok(false));
+        assert_eq!(out, "004-001000-7: valid (0040010007)\n\
+                         0040010008: invalid iranian national id number: check digit should be 7, found 8\n");
+        assert_eq!(run_to_string(&["validate", "0040010007"]).0, Ok(true));
+    }
+
+    #[test]
+    fn test_validate_jsonl_from_stdin() {
+        let stdin = "{\"national_id\": \"0040010007\"}\n\n{\"national_id\": 40010008}\n{\"name\": \"x\"}\n{oops\n";
+        let (result, out) = run_with_stdin(&["validate"], stdin);
+        assert_eq!(result, Ok(false));
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines, vec![
+            "line,national_id,status,error",
+            "1,0040010007,valid,",
+            "3,40010008,invalid,\"invalid iranian national id number: check digit should be 7, found 8\"",
+            "4,,invalid,missing field `national_id`",
+            "5,,invalid,\"malformed json: expected `\"\"`\"",
+        ]);
+    }
+
+    #[test]
+    fn test_validate_csv_column() {
+        let csv = "name,code\n\"Doe, J\",004-001000-7\nX,0814659438\n";
+        let (result, out) = run_with_stdin(&["validate", "--input", "-", "--input-format", "csv", "--column", "code"], csv);
+        assert_eq!(result, Ok(true));
+        assert_eq!(out, "line,code,status,error\n2,004-001000-7,valid,\n3,0814659438,valid,\n");
+
+        let (result, _) = run_with_stdin(&["validate", "--input-format", "csv", "--column", "nope"], csv);
+        assert_eq!(result, Err("no column `nope` in the csv header".to_string()));
+    }
+
+    #[test]
+    fn test_output_formats() {
+        let (result, out) = run_to_string(&["validate", "--format", "csv", "0040010007", "x"]);
+        assert_eq!(result, Ok(false));
+        assert_eq!(out, "line,national_id,status,error\n1,0040010007,valid,\n2,x,invalid,invalid iranian national id number: contains non-digit characters\n");
+
+        let (_, out) = run_to_string(&["validate", "--format", "json", "40010007", "0040010008"]);
+        assert_eq!(out, "{\"line\":1,\"input\":\"40010007\",\"valid\":true,\"normalized\":\"0040010007\"}\n\
+                         {\"line\":2,\"input\":\"0040010008\",\"valid\":false,\"error\":\"invalid iranian national id number: check digit should be 7, found 8\"}\n");
+
+        let (_, out) = run_with_stdin(&["validate", "--format", "plain"], "{\"national_id\": \"0040010007\"}\n");
+        assert_eq!(out, "0040010007: valid (0040010007)\n");
+        assert!(run_to_string(&["validate", "--format", "xml", "0040010007"]).0.is_err());
+    }
+
+    #[test]
+    fn test_quiet_only_sets_status() {
+        assert_eq!(run_to_string(&["validate", "-q", "0040010007"]), (Ok(true), String::new()));
+        assert_eq!(run_to_string(&["validate", "--quiet", "0040010008"]), (Ok(false), String::new()));
+        assert_eq!(run_with_stdin(&["validate", "--quiet"], "{\"national_id\": 1}\n"), (Ok(false), String::new()));
+    }
+
+    #[test]
+    fn test_generate_is_seeded() {
+        let (result, out) = run_to_string(&["generate", "--count", "3", "--seed", "7"]);
+        assert_eq!(result, Ok(true));
+        assert_eq!(out.lines().count(), 3);
+        assert_eq!(run_to_string(&["generate", "-n", "3", "--seed", "7"]).1, out);
+
+        let (_, out) = run_to_string(&["generate", "--seed", "1", "--province", "IR-23"]);
+        let id: inin::NationalId = out.trim().parse().unwrap();
+        assert_eq!(id.issuance_location().unwrap().province, inin::Province::Tehran);
+        for province in inin::Province::all() {
+            let (result, _) = run_to_string(&["generate", "--seed", "1", "--province", province.iso_code()]);
+            assert_eq!(result, Ok(true), "{}", province);
+        }
+
+        let (result, out) = run_to_string(&["generate", "-n", "5", "--seed", "7", "--distribution", "population"]);
+        assert_eq!(result, Ok(true));
+        assert!(out.lines().all(|line| line.parse::<inin::NationalId>().unwrap().issuance_location().is_some()));
+    }
+
+    #[test]
+    fn test_info() {
+        let (result, out) = run_to_string(&["info", "0040010007"]);
+        assert_eq!(result, Ok(true));
+        assert_eq!(out, "normalized: 0040010007\nissued in: Tehran, Tehran\n");
+    }
+
+    #[test]
+    fn test_usage_errors() {
+        assert!(run_to_string(&[]).0.is_err());
+        assert!(run_to_string(&["frobnicate"]).0.is_err());
+        assert!(run_to_string(&["validate", "--column"]).0.is_err());
+        assert!(run_to_string(&["generate", "--count"]).0.is_err());
+        assert!(run_to_string(&["generate", "--count", "x"]).0.is_err());
+        assert!(run_to_string(&["generate", "--distribution", "normal"]).0.is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Escapes `value` for use inside a JSON string literal.
+pub fn json_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Looks up a top-level key of a single-line JSON object and returns its value as text:
+/// strings are unescaped, numbers and booleans are returned verbatim, and `null`,
+/// objects and arrays yield `None`. `Ok(None)` is also returned when the key is absent.
+pub fn json_field(line: &str, key: &str) -> Result<Option<String>, String> {
+    let mut chars = line.chars().peekable();
+    let mut found = None;
+
+    skip_whitespace(&mut chars);
+    expect(&mut chars, '{')?;
+    skip_whitespace(&mut chars);
+    if chars.peek() == Some(&'}') {
+        chars.next();
+    } else {
+        loop {
+            skip_whitespace(&mut chars);
+            expect(&mut chars, '"')?;
+            let name = json_string(&mut chars)?;
+            skip_whitespace(&mut chars);
+            expect(&mut chars, ':')?;
+            skip_whitespace(&mut chars);
+            let value = json_value(&mut chars)?;
+            if name == key && found.is_none() {
+                found = Some(value);
+            }
+
+            skip_whitespace(&mut chars);
+            match chars.next() {
+                Some(',') => continue,
+                Some('}') => break,
+                _ => return Err("expected `,` or `}`".to_string()),
+            }
+        }
+    }
+
+    skip_whitespace(&mut chars);
+    if chars.next().is_some() {
+        return Err("trailing characters after object".to_string());
+    }
+    Ok(found.flatten())
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars<'_>>) {
+    while chars.peek().is_some_and(|c| c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+fn expect(chars: &mut Peekable<Chars<'_>>, expected: char) -> Result<(), String> {
+    match chars.next() {
+        Some(c) if c == expected => Ok(()),
+        _ => Err(format!("expected `{}`", expected)),
+    }
+}
+
+/// Reads the rest of a string whose opening quote was already consumed.
+fn json_string(chars: &mut Peekable<Chars<'_>>) -> Result<String, String> {
+    let mut value = String::new();
+    loop {
+        match chars.next().ok_or("unterminated string")? {
+            '"' => return Ok(value),
+            '\\' => match chars.next().ok_or("unterminated string")? {
+                'n' => value.push('\n'),
+                't' => value.push('\t'),
+                'r' => value.push('\r'),
+                'b' => value.push('\u{8}'),
+                'f' => value.push('\u{c}'),
+                'u' => {
+                    let code: String = chars.by_ref().take(4).collect();
+                    let code = u32::from_str_radix(&code, 16).map_err(|_| "invalid unicode escape")?;
+                    value.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                }
+                c => value.push(c),
+            },
+            c => value.push(c),
+        }
+    }
+}
+
+fn json_value(chars: &mut Peekable<Chars<'_>>) -> Result<Option<String>, String> {
+    match chars.peek().copied().ok_or("missing value")? {
+        '"' => {
+            chars.next();
+            json_string(chars).map(Some)
+        }
+        '{' | '[' => {
+            skip_nested(chars)?;
+            Ok(None)
+        }
+        _ => {
+            let mut token = String::new();
+            while let Some(c) = chars.peek().copied() {
+                if c == ',' || c == '}' || c.is_whitespace() {
+                    break;
+                }
+                token.push(c);
+                chars.next();
+            }
+            match token.as_str() {
+                "" => Err("missing value".to_string()),
+                "null" => Ok(None),
+                _ => Ok(Some(token)),
+            }
+        }
+    }
+}
+
+/// Skips a balanced object or array, including any strings inside it.
+fn skip_nested(chars: &mut Peekable<Chars<'_>>) -> Result<(), String> {
+    let mut depth = 0;
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | '[' => depth += 1,
+            '}' | ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(());
+                }
+            }
+            '"' => {
+                json_string(chars)?;
+            }
+            _ => {}
+        }
+    }
+    Err("unterminated object or array".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{json_escape, json_field};
+
+    #[test]
+    fn test_json_field() {
+        let line = r#"{"name": "J \"K\"", "tags": ["a", {"b": "}"}], "national_id": "0040010007"}"#;
+        assert_eq!(json_field(line, "national_id"), Ok(Some("0040010007".to_string())));
+        assert_eq!(json_field(line, "name"), Ok(Some("J \"K\"".to_string())));
+        assert_eq!(json_field(line, "tags"), Ok(None));
+        assert_eq!(json_field(line, "missing"), Ok(None));
+        assert_eq!(json_field(r#"{"national_id": 40010007}"#, "national_id"), Ok(Some("40010007".to_string())));
+        assert_eq!(json_field(r#"{"national_id": null}"#, "national_id"), Ok(None));
+        assert_eq!(json_field("{}", "national_id"), Ok(None));
+        assert!(json_field(r#"{"national_id": "0040010007""#, "national_id").is_err());
+        assert!(json_field("not json", "national_id").is_err());
+
+        let escaped = json_escape("a \"b\"\\\n\u{1}");
+        assert_eq!(escaped, r#"a \"b\"\\\n\u0001"#);
+        assert_eq!(json_field(&format!("{{\"v\": \"{}\"}}", escaped), "v"), Ok(Some("a \"b\"\\\n\u{1}".to_string())));
+    }
+}
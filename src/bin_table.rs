@@ -0,0 +1,202 @@
+#[cfg(feature = "std")]
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+use crate::bank::Bank;
+use crate::bank_card::{leading_bin, BINS};
+use crate::json;
+
+/// A Shetab BIN allocation table loaded at runtime, for when the bundled snapshot used by
+/// [`BankCard::issuer`](crate::BankCard::issuer) is out of date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinTable {
+    entries: Vec<([u8; 6], Bank)>,
+}
+
+impl BinTable {
+    /// A copy of the bundled snapshot, to extend or override.
+    pub fn bundled() -> Self {
+        let entries = BINS.iter().map(|(bin, bank)| (parse_bin(bin).unwrap(), *bank)).collect();
+        BinTable { entries }
+    }
+
+    /// Parses `bin,bank` lines, e.g. `603799,017`, where the bank is given by its
+    /// three-digit code as in [`Bank::from_code`].
+    ///
+    /// Blank lines, lines starting with `#` and a leading `bin,bank` header are skipped.
+    /// Later lines override earlier ones for the same BIN.
+    pub fn from_csv(text: &str) -> Result<Self, BinTableError> {
+        let mut table = BinTable { entries: Vec::new() };
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || (i == 0 && line.eq_ignore_ascii_case("bin,bank")) {
+                continue;
+            }
+
+            let number = i + 1;
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let (bin, bank) = match fields[..] {
+                [bin, bank] => (bin, bank),
+                _ => return Err(BinTableError::InvalidLine(number)),
+            };
+            table.insert_parsed(bin, bank, number)?;
+        }
+        Ok(table)
+    }
+
+    /// Parses a JSON object mapping BINs to three-digit bank codes, e.g.
+    /// `{"603799": "017", "610433": "012"}`. Later keys override earlier ones.
+    pub fn from_json(text: &str) -> Result<Self, BinTableError> {
+        let mut table = BinTable { entries: Vec::new() };
+        for (line, bin, bank) in json::string_object(text).map_err(BinTableError::InvalidJson)? {
+            table.insert_parsed(&bin, &bank, line)?;
+        }
+        Ok(table)
+    }
+
+    /// Reads a table in the format of [`BinTable::from_csv`].
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: std::io::Read>(mut reader: R) -> Result<Self, BinTableError> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text).map_err(|err| BinTableError::Io(err.kind()))?;
+        BinTable::from_csv(&text)
+    }
+
+    fn insert_parsed(&mut self, bin: &str, bank: &str, line: usize) -> Result<(), BinTableError> {
+        let bin = parse_bin(bin).ok_or(BinTableError::InvalidBin(line))?;
+        let bank = Bank::from_code(bank).ok_or(BinTableError::UnknownBank(line))?;
+        self.insert(bin, bank);
+        Ok(())
+    }
+
+    fn insert(&mut self, bin: [u8; 6], bank: Bank) {
+        match self.entries.iter_mut().find(|(b, _)| *b == bin) {
+            Some(entry) => *entry = (bin, bank),
+            None => self.entries.push((bin, bank)),
+        }
+    }
+
+    /// Adds the entries of `other`, replacing those with the same BIN; e.g. to apply
+    /// updated allocations on top of [`BinTable::bundled`].
+    pub fn extend(&mut self, other: &BinTable) {
+        for (bin, bank) in &other.entries {
+            self.insert(*bin, *bank);
+        }
+    }
+
+    /// The bank a card number, possibly incomplete, was issued by; like
+    /// [`issuer_from_prefix`](crate::bank_card::issuer_from_prefix), at least six digits
+    /// are needed.
+    pub fn issuer(&self, card_number: &str) -> Option<Bank> {
+        let bin = leading_bin(card_number)?;
+        self.entries.iter().find(|(b, _)| *b == bin).map(|(_, bank)| *bank)
+    }
+
+    /// BINs allocated to `bank`, in table order.
+    pub fn bins_of(&self, bank: Bank) -> impl Iterator<Item = &str> {
+        self.entries.iter()
+            .filter(move |(_, b)| *b == bank)
+            .map(|(bin, _)| core::str::from_utf8(bin).unwrap())
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+fn parse_bin(bin: &str) -> Option<[u8; 6]> {
+    match bin.as_bytes() {
+        digits @ [_, _, _, _, _, _] if digits.iter().all(u8::is_ascii_digit) => {
+            Some([digits[0], digits[1], digits[2], digits[3], digits[4], digits[5]])
+        }
+        _ => None,
+    }
+}
+
+/// Why a BIN table could not be loaded; line numbers start at 1.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BinTableError {
+    InvalidLine(usize),
+    InvalidBin(usize),
+    UnknownBank(usize),
+    InvalidJson(usize),
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+impl Error for BinTableError {}
+
+impl Display for BinTableError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BinTableError::InvalidLine(line) => write!(f, "invalid bin table: line {} is not bin,bank", line),
+            BinTableError::InvalidBin(line) => write!(f, "invalid bin table: line {} does not have a six-digit bin", line),
+            BinTableError::UnknownBank(line) => write!(f, "invalid bin table: unknown bank code on line {}", line),
+            BinTableError::InvalidJson(line) => {
+                write!(f, "invalid bin table: line {} is not part of a json object of strings", line)
+            }
+            #[cfg(feature = "std")]
+            BinTableError::Io(kind) => write!(f, "could not read bin table: {}", kind),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::bank::Bank;
+    use crate::bank_card::BankCard;
+    use crate::bin_table::{BinTable, BinTableError};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_load_csv() {
+        let csv = "bin,bank\n# merged into Sepah\n639217,015\n\n505809,062\n";
+        let table = BinTable::from_csv(csv).unwrap();
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.issuer("6392-17"), Some(Bank::Sepah));
+        assert_eq!(table.issuer("603799"), None);
+        assert_eq!(table.bins_of(Bank::Ayandeh).collect::<Vec<_>>(), vec!["505809"]);
+        assert_eq!(BinTable::from_reader(csv.as_bytes()), Ok(table));
+    }
+
+    #[test]
+    fn test_load_json() {
+        let json = "{\n  \"639217\": \"015\",\n  \"505809\" : \"062\"\n}\n";
+        assert_eq!(BinTable::from_json(json), BinTable::from_csv("639217,015\n505809,062"));
+        assert_eq!(BinTable::from_json(" {} ").unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_bundled_copy_and_overrides() {
+        let card = BankCard::try_from("6219861034529007").unwrap();
+        let mut table = BinTable::bundled();
+        assert_eq!(table.issuer("603799"), Some(Bank::Melli));
+        assert_eq!(card.issuer_in(&table), Some(Bank::Saman));
+
+        table.extend(&BinTable::from_json(r#"{"603799": "015", "111111": "017"}"#).unwrap());
+        assert_eq!(table.issuer("603799"), Some(Bank::Sepah));
+        assert_eq!(table.issuer("1111 1122"), Some(Bank::Melli));
+        assert_eq!(table.len(), BinTable::bundled().len() + 1);
+    }
+
+    #[test]
+    fn test_invalid_table() {
+        assert_eq!(BinTable::from_csv("603799"), Err(BinTableError::InvalidLine(1)));
+        assert_eq!(BinTable::from_csv("\n60379,017"), Err(BinTableError::InvalidBin(2)));
+        assert_eq!(BinTable::from_csv("603799,999"), Err(BinTableError::UnknownBank(1)));
+        assert_eq!(BinTable::from_json("{\n\"603799\": \"999\"}"), Err(BinTableError::UnknownBank(2)));
+        assert_eq!(BinTable::from_json("{\"603799\": 17}"), Err(BinTableError::InvalidJson(1)));
+        assert_eq!(BinTable::from_json("{\"603799\": \"017\",\n"), Err(BinTableError::InvalidJson(2)));
+        assert_eq!(BinTable::from_json("[]"), Err(BinTableError::InvalidJson(1)));
+        assert_eq!(
+            BinTableError::UnknownBank(3).to_string(),
+            "invalid bin table: unknown bank code on line 3"
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::locale::LocalizedError;
+use crate::normalize::{is_separator, normalize_digit, to_persian_digits};
+
+/// Letters of birth certificate series. `ا` stands for `الف`.
+const LETTERS: [char; 32] = [
+    'ا', 'ب', 'پ', 'ت', 'ث', 'ج', 'چ', 'ح', 'خ', 'د', 'ذ', 'ر', 'ز', 'ژ', 'س', 'ش',
+    'ص', 'ض', 'ط', 'ظ', 'ع', 'غ', 'ف', 'ق', 'ک', 'گ', 'ل', 'م', 'ن', 'و', 'ه', 'ی',
+];
+
+/// The letters as written on the certificate, in the order of [`LETTERS`].
+const LETTER_NAMES: [&str; 32] = [
+    "الف", "ب", "پ", "ت", "ث", "ج", "چ", "ح", "خ", "د", "ذ", "ر", "ز", "ژ", "س", "ش",
+    "ص", "ض", "ط", "ظ", "ع", "غ", "ف", "ق", "ک", "گ", "ل", "م", "ن", "و", "ه", "ی",
+];
+
+/// Birth certificate number (شماره شناسنامه) of up to 10 digits.
+///
+/// Certificates issued since 1368 carry the holder's national id as their number, so
+/// leading zeros are kept as written.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct BirthCertificateNumber(String);
+
+impl TryFrom<&str> for BirthCertificateNumber {
+    type Error = BirthCertificateError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let number: String = value.chars().filter(|c| !is_separator(*c)).map(normalize_digit).collect();
+        if !number.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(BirthCertificateError::NonDigitCharacter);
+        }
+        if !(1..=10).contains(&number.len()) {
+            return Err(BirthCertificateError::InvalidLength(number.len()));
+        }
+        if number.bytes().all(|b| b == b'0') {
+            return Err(BirthCertificateError::Zero);
+        }
+        Ok(BirthCertificateNumber(number))
+    }
+}
+
+impl TryFrom<String> for BirthCertificateNumber {
+    type Error = BirthCertificateError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        BirthCertificateNumber::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for BirthCertificateNumber {
+    type Error = BirthCertificateError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        BirthCertificateNumber::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<u64> for BirthCertificateNumber {
+    type Error = BirthCertificateError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        BirthCertificateNumber::try_from(value.to_string())
+    }
+}
+
+impl FromStr for BirthCertificateNumber {
+    type Err = BirthCertificateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BirthCertificateNumber::try_from(s)
+    }
+}
+
+impl BirthCertificateNumber {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl AsRef<str> for BirthCertificateNumber {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for BirthCertificateNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The serial printed on a birth certificate: a Persian letter, a series number of one or
+/// two digits and a six-digit serial, written e.g. `الف/12 345678` or `۳۴۵۶۷۸ - ۱۲ ب`.
+///
+/// The letter may come before or after the numbers. Without a separator between them,
+/// the last six digits are the serial.
+#[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct BirthCertificateSeries {
+    letter: char,
+    series: u8,
+    serial: u32,
+}
+
+fn tokens(value: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = Vec::new();
+    let mut last_is_digit = None;
+    for c in value.replace("الف", "ا").chars() {
+        if is_separator(c) || c == '/' {
+            last_is_digit = None;
+            continue;
+        }
+        let c = match normalize_digit(c) {
+            'ي' | 'ى' => 'ی',
+            'ك' => 'ک',
+            'آ' => 'ا',
+            c => c,
+        };
+        match tokens.last_mut() {
+            Some(token) if last_is_digit == Some(c.is_ascii_digit()) => token.push(c),
+            _ => tokens.push(c.to_string()),
+        }
+        last_is_digit = Some(c.is_ascii_digit());
+    }
+    tokens
+}
+
+impl TryFrom<&str> for BirthCertificateSeries {
+    type Error = BirthCertificateError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let tokens = tokens(value);
+        let (letters, numbers): (Vec<&String>, Vec<&String>) =
+            tokens.iter().partition(|token| !token.starts_with(|c: char| c.is_ascii_digit()));
+
+        let letter = match letters.as_slice() {
+            [letter] if letter.chars().count() == 1 => letter.chars().next().unwrap(),
+            _ => return Err(BirthCertificateError::InvalidFormat),
+        };
+        if !LETTERS.contains(&letter) {
+            return Err(BirthCertificateError::UnknownLetter(letter));
+        }
+
+        let (series, serial) = match numbers.as_slice() {
+            [digits] if (7..=8).contains(&digits.len()) => digits.split_at(digits.len() - 6),
+            [a, b] if a.len() == 6 && (1..=2).contains(&b.len()) => (b.as_str(), a.as_str()),
+            [a, b] if b.len() == 6 && (1..=2).contains(&a.len()) => (a.as_str(), b.as_str()),
+            _ => return Err(BirthCertificateError::InvalidFormat),
+        };
+        let series: u8 = series.parse().unwrap();
+        if series == 0 {
+            return Err(BirthCertificateError::Zero);
+        }
+        Ok(BirthCertificateSeries { letter, series, serial: serial.parse().unwrap() })
+    }
+}
+
+impl TryFrom<String> for BirthCertificateSeries {
+    type Error = BirthCertificateError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        BirthCertificateSeries::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for BirthCertificateSeries {
+    type Error = BirthCertificateError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        BirthCertificateSeries::try_from(value.as_str())
+    }
+}
+
+impl FromStr for BirthCertificateSeries {
+    type Err = BirthCertificateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BirthCertificateSeries::try_from(s)
+    }
+}
+
+impl BirthCertificateSeries {
+    /// The series letter; `ا` stands for `الف`.
+    pub fn letter(&self) -> char {
+        self.letter
+    }
+
+    /// The series letter as written on the certificate, e.g. `الف`.
+    pub fn letter_name(&self) -> &'static str {
+        LETTER_NAMES[LETTERS.iter().position(|c| *c == self.letter).unwrap()]
+    }
+
+    pub fn series(&self) -> u8 {
+        self.series
+    }
+
+    pub fn serial(&self) -> u32 {
+        self.serial
+    }
+
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(&self.to_string())
+    }
+}
+
+/// The letter, series and serial, e.g. `الف/12 345678`.
+impl Display for BirthCertificateSeries {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}/{} {:06}", self.letter_name(), self.series, self.serial)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum BirthCertificateError {
+    InvalidLength(usize),
+    NonDigitCharacter,
+    Zero,
+    InvalidFormat,
+    UnknownLetter(char),
+}
+
+impl Error for BirthCertificateError {}
+
+impl Display for BirthCertificateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BirthCertificateError::InvalidLength(len) => {
+                write!(f, "invalid birth certificate: expected 1 to 10 digits, got {}", len)
+            }
+            BirthCertificateError::NonDigitCharacter => {
+                write!(f, "invalid birth certificate: contains non-digit characters")
+            }
+            BirthCertificateError::Zero => write!(f, "invalid birth certificate: number is zero"),
+            BirthCertificateError::InvalidFormat => {
+                write!(f, "invalid birth certificate: series should be a letter, a series number and a six-digit serial")
+            }
+            BirthCertificateError::UnknownLetter(letter) => {
+                write!(f, "invalid birth certificate: unknown series letter `{}`", letter)
+            }
+        }
+    }
+}
+
+impl LocalizedError for BirthCertificateError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "شناسنامه نامعتبر است: ")?;
+        match self {
+            BirthCertificateError::InvalidLength(len) => write!(f, "باید ۱ تا ۱۰ رقم باشد، {} رقم وارد شده", len),
+            BirthCertificateError::NonDigitCharacter => write!(f, "فقط باید شامل رقم باشد"),
+            BirthCertificateError::Zero => write!(f, "شماره صفر است"),
+            BirthCertificateError::InvalidFormat => write!(f, "سری باید شامل یک حرف، شماره سری و سریال ۶ رقمی باشد"),
+            BirthCertificateError::UnknownLetter(letter) => write!(f, "حرف سری «{}» شناخته نشد", letter),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::birth_certificate::{BirthCertificateError, BirthCertificateNumber, BirthCertificateSeries};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_validate_birth_certificate_number() {
+        assert_eq!(BirthCertificateNumber::try_from(" ۱۲۳۴ ").unwrap().as_str(), "1234");
+        assert_eq!(BirthCertificateNumber::try_from("0040010007").unwrap().to_string(), "0040010007");
+        assert_eq!(BirthCertificateNumber::try_from(1234u64).unwrap().to_persian_digits(), "۱۲۳۴");
+        assert_eq!(BirthCertificateNumber::try_from("12345678901"), Err(BirthCertificateError::InvalidLength(11)));
+        assert_eq!(BirthCertificateNumber::try_from("12a4"), Err(BirthCertificateError::NonDigitCharacter));
+        assert_eq!(BirthCertificateNumber::try_from("000"), Err(BirthCertificateError::Zero));
+    }
+
+    #[test]
+    fn test_parse_birth_certificate_series() {
+        let series = BirthCertificateSeries::try_from("الف/۱۲ ۳۴۵۶۷۸").unwrap();
+        assert_eq!((series.letter(), series.series(), series.serial()), ('ا', 12, 345678));
+        assert_eq!(series.letter_name(), "الف");
+        assert_eq!(series.to_string(), "الف/12 345678");
+        assert_eq!(series.to_persian_digits(), "الف/۱۲ ۳۴۵۶۷۸");
+
+        assert_eq!(BirthCertificateSeries::try_from("345678 - 12 الف"), Ok(series));
+        assert_eq!(BirthCertificateSeries::try_from("12ا345678"), Ok(series));
+        assert_eq!(BirthCertificateSeries::try_from("آ 12/345678"), Ok(series));
+
+        let series = BirthCertificateSeries::try_from("ي 3 012345").unwrap();
+        assert_eq!(series.to_string(), "ی/3 012345");
+    }
+
+    #[test]
+    fn test_invalid_birth_certificate_series() {
+        assert_eq!(BirthCertificateSeries::try_from("12 345678"), Err(BirthCertificateError::InvalidFormat));
+        assert_eq!(BirthCertificateSeries::try_from("ب 12 34567"), Err(BirthCertificateError::InvalidFormat));
+        assert_eq!(BirthCertificateSeries::try_from("ب ج 12 345678"), Err(BirthCertificateError::InvalidFormat));
+        assert_eq!(BirthCertificateSeries::try_from("x 12 345678"), Err(BirthCertificateError::UnknownLetter('x')));
+        assert_eq!(BirthCertificateSeries::try_from("ب 00 345678"), Err(BirthCertificateError::Zero));
+    }
+}
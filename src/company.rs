@@ -0,0 +1,144 @@
+use core::convert::TryFrom;
+
+use crate::economic_code::{EconomicCode, EconomicCodeKind};
+use crate::fields::{FieldError, FieldErrors};
+use crate::legal_national_id::LegalNationalId;
+use crate::registration_number::RegistrationNumber;
+use crate::sheba::Sheba;
+
+/// The identifiers of a company collected during B2B onboarding, validated together.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Company {
+    pub legal_national_id: LegalNationalId,
+    pub registration_number: RegistrationNumber,
+    pub economic_code: EconomicCode,
+    pub sheba: Sheba,
+}
+
+impl Company {
+    pub fn builder<'a>() -> CompanyBuilder<'a> {
+        CompanyBuilder::default()
+    }
+}
+
+/// Collects the raw field values of a [`Company`]; every field is required.
+///
+/// Errors are keyed by the field names `legal_national_id`, `registration_number`,
+/// `economic_code` and `sheba`. An 11-digit economic code is the company's legal
+/// national id, so a different one is reported as a [`FieldError::Mismatch`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CompanyBuilder<'a> {
+    legal_national_id: Option<&'a str>,
+    registration_number: Option<&'a str>,
+    economic_code: Option<&'a str>,
+    sheba: Option<&'a str>,
+}
+
+impl<'a> CompanyBuilder<'a> {
+    pub fn legal_national_id(mut self, value: &'a str) -> Self {
+        self.legal_national_id = Some(value);
+        self
+    }
+
+    pub fn registration_number(mut self, value: &'a str) -> Self {
+        self.registration_number = Some(value);
+        self
+    }
+
+    pub fn economic_code(mut self, value: &'a str) -> Self {
+        self.economic_code = Some(value);
+        self
+    }
+
+    pub fn sheba(mut self, value: &'a str) -> Self {
+        self.sheba = Some(value);
+        self
+    }
+
+    /// Validates every field, returning the errors of all invalid ones.
+    pub fn build(&self) -> Result<Company, FieldErrors> {
+        let mut errors = FieldErrors::default();
+
+        let legal_national_id = errors.check("legal_national_id", self.legal_national_id, |value| {
+            LegalNationalId::try_from(value).map_err(|err| FieldError::Invalid(err.into()))
+        });
+        let registration_number = errors.check("registration_number", self.registration_number, |value| {
+            RegistrationNumber::try_from(value).map_err(|err| FieldError::Invalid(err.into()))
+        });
+        let economic_code = errors.check("economic_code", self.economic_code, |value| {
+            EconomicCode::try_from(value).map_err(|err| FieldError::Invalid(err.into()))
+        });
+        let sheba = errors.check("sheba", self.sheba, |value| {
+            Sheba::try_from(value).map_err(|err| FieldError::Invalid(err.into()))
+        });
+
+        if let (Some(id), Some(code)) = (&legal_national_id, &economic_code) {
+            if code.kind() == EconomicCodeKind::Legal && code.as_str() != id.as_str() {
+                errors.insert("economic_code", FieldError::Mismatch("legal_national_id"));
+            }
+        }
+
+        match (legal_national_id, registration_number, economic_code, sheba) {
+            (Some(legal_national_id), Some(registration_number), Some(economic_code), Some(sheba))
+                if errors.is_empty() =>
+            {
+                Ok(Company { legal_national_id, registration_number, economic_code, sheba })
+            }
+            _ => Err(errors),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::company::Company;
+    use crate::fields::FieldError;
+    use crate::registration_number::RegistrationNumberError;
+
+    #[test]
+    fn test_valid_company() {
+        let company = Company::builder()
+            .legal_national_id("10380284790")
+            .registration_number("۱۲۳۴۵")
+            .economic_code("10380284790")
+            .sheba("IR06 2960 0000 0010 0324 2000 01")
+            .build()
+            .unwrap();
+        assert_eq!(company.registration_number.as_str(), "12345");
+        assert_eq!(company.sheba.as_str(), "IR062960000000100324200001");
+
+        let legacy = Company::builder()
+            .legal_national_id("10380284790")
+            .registration_number("12345")
+            .economic_code("411112345678")
+            .sheba("IR062960000000100324200001")
+            .build();
+        assert!(legacy.is_ok());
+    }
+
+    #[test]
+    fn test_reports_every_invalid_field() {
+        let errors = Company::builder()
+            .legal_national_id("10380284790")
+            .registration_number("0")
+            .economic_code("0040010007")
+            .build()
+            .unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors.get("registration_number"),
+            Some(&FieldError::Invalid(RegistrationNumberError::Zero.into()))
+        );
+        assert_eq!(errors.get("sheba"), Some(&FieldError::Missing));
+
+        let errors = Company::builder()
+            .legal_national_id("10380284790")
+            .registration_number("12345")
+            .economic_code("14007650912")
+            .sheba("IR062960000000100324200001")
+            .build()
+            .unwrap_err();
+        assert_eq!(errors.get("economic_code"), Some(&FieldError::Mismatch("legal_national_id")));
+        assert_eq!(errors.to_string(), "economic_code: does not match legal_national_id");
+    }
+}
@@ -0,0 +1,225 @@
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use std::borrow::Cow;
+use std::io::{self, BufRead, Write};
+
+use crate::identifier::Identifier;
+
+/// The column of a CSV file holding the identifiers to check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column<'a> {
+    /// A column named in the header row; surrounding whitespace is ignored.
+    Name(&'a str),
+    /// A zero-based column index; the first row is still treated as a header.
+    Index(usize),
+}
+
+/// Outcome of [`validate_column`] and [`clean_column`], by one-based line number.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnReport<T: Identifier> {
+    /// Number of data rows checked; blank lines are skipped.
+    pub total: usize,
+    pub valid: Vec<(usize, T)>,
+    pub failures: Vec<RowFailure<T::Error>>,
+}
+
+impl<T: Identifier> ColumnReport<T> {
+    pub fn is_all_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl<T: Identifier> Default for ColumnReport<T> {
+    fn default() -> Self {
+        ColumnReport { total: 0, valid: Vec::new(), failures: Vec::new() }
+    }
+}
+
+/// A row whose column did not hold a valid identifier.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowFailure<E> {
+    pub line: usize,
+    /// The field as read, or empty if the row has no such column.
+    pub value: String,
+    /// `None` if the row has fewer fields than the column index.
+    pub error: Option<E>,
+}
+
+impl<E: Display> Display for RowFailure<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match &self.error {
+            Some(error) => write!(f, "line {}: {}", self.line, error),
+            None => write!(f, "line {}: missing column", self.line),
+        }
+    }
+}
+
+/// Why a CSV file could not be checked at all.
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum CsvError {
+    /// The input has no header row.
+    Empty,
+    /// The header has no column of this name.
+    UnknownColumn(String),
+    Io(io::ErrorKind),
+}
+
+impl Error for CsvError {}
+
+impl Display for CsvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CsvError::Empty => write!(f, "could not read csv: input is empty"),
+            CsvError::UnknownColumn(name) => write!(f, "could not read csv: no column `{}` in the header", name),
+            CsvError::Io(kind) => write!(f, "could not read csv: {}", kind),
+        }
+    }
+}
+
+/// Validates `column` of every row of a CSV file with a header row.
+pub fn validate_column<T: Identifier, R: BufRead>(reader: R, column: Column<'_>) -> Result<ColumnReport<T>, CsvError> {
+    read_column(reader, column, |_| Ok(()))
+}
+
+/// Like [`validate_column`], also writing the header and every valid row to `writer`
+/// with the column replaced by its [`Identifier::normalized`] form. Invalid rows are
+/// left out of the output and listed in the report.
+pub fn clean_column<T: Identifier, R: BufRead, W: Write>(
+    reader: R,
+    mut writer: W,
+    column: Column<'_>,
+) -> Result<ColumnReport<T>, CsvError> {
+    read_column::<T, _>(reader, column, |fields| {
+        let line: Vec<Cow<'_, str>> = fields.iter().map(|field| escape(field)).collect();
+        writeln!(writer, "{}", line.join(","))
+    })
+}
+
+/// Reads the header and rows, calling `write` with the header fields and with the fields
+/// of every valid row after normalizing the column.
+fn read_column<T: Identifier, R: BufRead>(
+    reader: R,
+    column: Column<'_>,
+    mut write: impl FnMut(&[String]) -> io::Result<()>,
+) -> Result<ColumnReport<T>, CsvError> {
+    let mut lines = reader.lines().enumerate();
+    let header = match lines.next() {
+        Some((_, header)) => fields(&header.map_err(|err| CsvError::Io(err.kind()))?),
+        None => return Err(CsvError::Empty),
+    };
+    let index = match column {
+        Column::Name(name) => header.iter()
+            .position(|field| field.trim() == name.trim())
+            .ok_or_else(|| CsvError::UnknownColumn(name.to_string()))?,
+        Column::Index(index) => index,
+    };
+    write(&header).map_err(|err| CsvError::Io(err.kind()))?;
+
+    let mut report = ColumnReport::default();
+    for (n, line) in lines {
+        let line = line.map_err(|err| CsvError::Io(err.kind()))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        report.total += 1;
+        let mut fields = fields(&line);
+        let value = match fields.get(index) {
+            Some(value) => value,
+            None => {
+                report.failures.push(RowFailure { line: n + 1, value: String::new(), error: None });
+                continue;
+            }
+        };
+        match T::parse(value) {
+            Ok(id) => {
+                fields[index] = id.normalized();
+                write(&fields).map_err(|err| CsvError::Io(err.kind()))?;
+                report.valid.push((n + 1, id));
+            }
+            Err(error) => report.failures.push(RowFailure { line: n + 1, value: value.clone(), error: Some(error) }),
+        }
+    }
+    Ok(report)
+}
+
+/// Splits one CSV line into fields, honouring double-quoted fields and `""` escapes.
+///
+/// Quoted fields spanning several lines are not supported.
+pub fn fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// Quotes a CSV field when it contains a delimiter, quote or line break.
+pub fn escape(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csv::{clean_column, escape, fields, validate_column, Column, ColumnReport, CsvError};
+    use crate::national_id::{NationalId, NationalIdError};
+    use crate::postal_code::PostalCode;
+
+    const EXPORT: &str = "name,code\n\"Smith, J\",۰۰۴۰۰۱۰۰۰۷\nDoe,0040010008\n\nRoe\nPoe, 40010007\n";
+
+    #[test]
+    fn test_fields_and_escape() {
+        assert_eq!(fields("a,b,,c"), vec!["a", "b", "", "c"]);
+        assert_eq!(fields(r#""Smith, J","say ""hi""",0040010007"#), vec!["Smith, J", r#"say "hi""#, "0040010007"]);
+        assert_eq!(escape("plain"), "plain");
+        assert_eq!(escape(r#"a "b", c"#), r#""a ""b"", c""#);
+    }
+
+    #[test]
+    fn test_validate_column() {
+        let report: ColumnReport<NationalId> = validate_column(EXPORT.as_bytes(), Column::Name("code")).unwrap();
+        assert_eq!(report.total, 4);
+        assert_eq!(report.valid.iter().map(|(line, _)| *line).collect::<Vec<_>>(), vec![2, 6]);
+        assert_eq!(report.failures.len(), 2);
+        assert_eq!(report.failures[0].error, Some(NationalIdError::InvalidCheckDigit { expected: 7, found: 8 }));
+        assert_eq!(report.failures[1].to_string(), "line 5: missing column");
+        assert!(!report.is_all_valid());
+
+        let by_index: ColumnReport<NationalId> = validate_column(EXPORT.as_bytes(), Column::Index(1)).unwrap();
+        assert_eq!(by_index, report);
+    }
+
+    #[test]
+    fn test_clean_column() {
+        let mut out = Vec::new();
+        let report = clean_column::<NationalId, _, _>(EXPORT.as_bytes(), &mut out, Column::Name("code")).unwrap();
+        assert_eq!(report.valid.len(), 2);
+        assert_eq!(String::from_utf8(out).unwrap(), "name,code\n\"Smith, J\",0040010007\nPoe,0040010007\n");
+    }
+
+    #[test]
+    fn test_unreadable_csv() {
+        assert_eq!(validate_column::<PostalCode, _>(&b""[..], Column::Index(0)), Err(CsvError::Empty));
+        assert_eq!(
+            validate_column::<PostalCode, _>(EXPORT.as_bytes(), Column::Name("zip")),
+            Err(CsvError::UnknownColumn("zip".to_string()))
+        );
+    }
+}
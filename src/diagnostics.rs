@@ -0,0 +1,264 @@
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+use crate::national_id::{check_digit, weighted_sum, NationalId, NationalIdError};
+use crate::normalize::normalize_digit;
+
+/// One problem found by [`NationalId::diagnose`](crate::NationalId::diagnose).
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum Issue {
+    InvalidLength(usize),
+    /// A character that is not a digit, with its index (in characters) in the input.
+    InvalidCharacter { position: usize, character: char },
+    AllZeros,
+    RepeatedDigits,
+    InvalidCheckDigit { expected: u8, found: u8 },
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Issue::InvalidLength(len) => write!(f, "expected at most 10 digits, got {}", len),
+            Issue::InvalidCharacter { position, character } => {
+                write!(f, "{:?} at position {} is not a digit", character, position)
+            }
+            Issue::AllZeros => write!(f, "all digits are zero"),
+            Issue::RepeatedDigits => write!(f, "all digits are identical"),
+            Issue::InvalidCheckDigit { expected, found } => {
+                write!(f, "check digit should be {}, found {}", expected, found)
+            }
+        }
+    }
+}
+
+/// Every problem found in one input, rather than just the first.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    pub issues: Vec<Issue>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.issues.is_empty() {
+            return write!(f, "valid iranian national id number");
+        }
+        write!(f, "invalid iranian national id number: ")?;
+        for (i, issue) in self.issues.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+/// Step-by-step check digit computation for one input, as returned by
+/// [`NationalId::explain_checksum`](crate::NationalId::explain_checksum).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecksumExplanation {
+    /// The input zero-padded to ten digits.
+    pub digits: [u8; 10],
+    /// Weight of each of the first nine digits, from 10 down to 2.
+    pub weights: [u8; 9],
+    pub sum: u32,
+    /// `sum % 11`.
+    pub remainder: u32,
+    /// The remainder itself if below 2, `11 - remainder` otherwise.
+    pub expected: u8,
+    /// The last digit of the input.
+    pub found: u8,
+}
+
+impl ChecksumExplanation {
+    pub fn is_valid(&self) -> bool {
+        self.sum != 0 && self.expected == self.found
+    }
+}
+
+/// Renders the computation, e.g.
+/// `0×10 + 0×9 + 4×8 + 0×7 + 0×6 + 1×5 + 0×4 + 0×3 + 0×2 = 37; 37 mod 11 = 4; 11 - 4 = 7; found 7`.
+impl Display for ChecksumExplanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for (i, (digit, weight)) in self.digits.iter().zip(&self.weights).enumerate() {
+            if i > 0 {
+                write!(f, " + ")?;
+            }
+            write!(f, "{}×{}", digit, weight)?;
+        }
+        write!(f, " = {}; {} mod 11 = {}; ", self.sum, self.sum, self.remainder)?;
+        if self.remainder < 2 {
+            write!(f, "{} < 2, so {}", self.remainder, self.expected)?;
+        } else {
+            write!(f, "11 - {} = {}", self.remainder, self.expected)?;
+        }
+        write!(f, "; found {}", self.found)
+    }
+}
+
+pub(crate) fn explain_checksum(input: &str) -> Result<ChecksumExplanation, NationalIdError> {
+    let value = input.trim();
+    let len = value.chars().count();
+    if len > 10 {
+        return Err(NationalIdError::InvalidLength(len));
+    }
+
+    let mut digits = [0u8; 10];
+    for (slot, c) in digits[10 - len..].iter_mut().zip(value.chars()) {
+        *slot = normalize_digit(c).to_digit(10).ok_or(NationalIdError::NonDigitCharacter)? as u8;
+    }
+
+    let mut weights = [0u8; 9];
+    for (i, weight) in weights.iter_mut().enumerate() {
+        *weight = 10 - i as u8;
+    }
+    let sum = digits.iter().zip(&weights).map(|(d, w)| (*d as u32) * (*w as u32)).sum::<u32>();
+    Ok(ChecksumExplanation {
+        digits,
+        weights,
+        sum,
+        remainder: sum % 11,
+        expected: check_digit(sum) as u8,
+        found: digits[9],
+    })
+}
+
+pub(crate) fn suggestions(input: &str) -> Vec<NationalId> {
+    let digits = match explain_checksum(input) {
+        Ok(explanation) if !explanation.is_valid() => explanation.digits,
+        _ => return Vec::new(),
+    };
+
+    let mut candidates = Vec::new();
+    let mut corrected = digits;
+    corrected[9] = check_digit(weighted_sum(&corrected.map(u32::from))) as u8;
+    candidates.push(corrected);
+    for i in 0..9 {
+        let mut swapped = digits;
+        swapped.swap(i, i + 1);
+        candidates.push(swapped);
+    }
+
+    let mut suggestions: Vec<NationalId> = Vec::new();
+    for candidate in candidates {
+        if let Ok(id) = NationalId::from_ascii_digits(&candidate.map(|d| b'0' + d)) {
+            if !suggestions.contains(&id) {
+                suggestions.push(id);
+            }
+        }
+    }
+    suggestions
+}
+
+pub(crate) fn diagnose(input: &str) -> ValidationReport {
+    let offset = input.chars().take_while(|c| c.is_whitespace()).count();
+    let value = input.trim();
+    let mut issues = Vec::new();
+
+    let len = value.chars().count();
+    if len > 10 {
+        issues.push(Issue::InvalidLength(len));
+    }
+
+    let mut digits = Vec::with_capacity(10);
+    for (i, c) in value.chars().enumerate() {
+        match normalize_digit(c).to_digit(10) {
+            Some(d) => digits.push(d),
+            None => issues.push(Issue::InvalidCharacter { position: offset + i, character: c }),
+        }
+    }
+    if !issues.is_empty() {
+        return ValidationReport { issues };
+    }
+
+    let mut padded = [0u32; 10];
+    padded[10 - len..].copy_from_slice(&digits);
+    let sum = weighted_sum(&padded);
+    if sum == 0 {
+        issues.push(Issue::AllZeros);
+    } else if padded.iter().all(|d| *d == padded[0]) {
+        issues.push(Issue::RepeatedDigits);
+    }
+
+    let expected = check_digit(sum);
+    if sum != 0 && expected != padded[9] {
+        issues.push(Issue::InvalidCheckDigit { expected: expected as u8, found: padded[9] as u8 });
+    }
+    ValidationReport { issues }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::diagnostics::Issue;
+    use crate::national_id::{NationalId, NationalIdError};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_valid_input_has_no_issues() {
+        let report = NationalId::diagnose("0040010007");
+        assert!(report.is_valid());
+        assert_eq!(report.to_string(), "valid iranian national id number");
+        assert!(NationalId::diagnose(" ۴۰۰۱۰۰۰۷ ").is_valid());
+    }
+
+    #[test]
+    fn test_collects_every_issue() {
+        let report = NationalId::diagnose(" 00a001000b07");
+        assert_eq!(report.issues, vec![
+            Issue::InvalidLength(12),
+            Issue::InvalidCharacter { position: 3, character: 'a' },
+            Issue::InvalidCharacter { position: 10, character: 'b' },
+        ]);
+        assert_eq!(
+            report.to_string(),
+            "invalid iranian national id number: expected at most 10 digits, got 12; \
+             'a' at position 3 is not a digit; 'b' at position 10 is not a digit"
+        );
+    }
+
+    #[test]
+    fn test_checksum_and_digit_patterns() {
+        assert_eq!(NationalId::diagnose("0040010008").issues, vec![Issue::InvalidCheckDigit { expected: 7, found: 8 }]);
+        assert_eq!(NationalId::diagnose("0000000000").issues, vec![Issue::AllZeros]);
+        assert_eq!(NationalId::diagnose("1111111111").issues, vec![Issue::RepeatedDigits]);
+        assert_eq!(NationalId::diagnose("2222222222").issues, vec![Issue::RepeatedDigits]);
+        assert_eq!(NationalId::diagnose("").issues, vec![Issue::AllZeros]);
+    }
+
+    #[test]
+    fn test_explain_checksum() {
+        let explanation = NationalId::explain_checksum("40010008").unwrap();
+        assert_eq!(explanation.digits, [0, 0, 4, 0, 0, 1, 0, 0, 0, 8]);
+        assert_eq!(explanation.weights, [10, 9, 8, 7, 6, 5, 4, 3, 2]);
+        assert_eq!((explanation.sum, explanation.remainder, explanation.expected, explanation.found), (37, 4, 7, 8));
+        assert!(!explanation.is_valid());
+        assert_eq!(
+            explanation.to_string(),
+            "0×10 + 0×9 + 4×8 + 0×7 + 0×6 + 1×5 + 0×4 + 0×3 + 0×2 = 37; 37 mod 11 = 4; 11 - 4 = 7; found 8"
+        );
+
+        let explanation = NationalId::explain_checksum("0814659438").unwrap();
+        assert!(explanation.is_valid());
+        assert!(explanation.to_string().ends_with("; found 8"));
+        assert_eq!(NationalId::explain_checksum("12345678ab"), Err(NationalIdError::NonDigitCharacter));
+    }
+
+    #[test]
+    fn test_suggestions() {
+        let id = |s| NationalId::try_from(s).unwrap();
+        // The third and fourth digits of 0814659438 swapped.
+        let suggestions = NationalId::suggestions("0841659438");
+        assert_eq!(suggestions, vec![id("0841659435"), id("8041659438"), id("0814659438")]);
+
+        assert_eq!(NationalId::suggestions("0040010008"), vec![id("0040010007"), id("0040001008")]);
+        assert!(NationalId::suggestions("0040010007").is_empty());
+        assert!(NationalId::suggestions("00400100a8").is_empty());
+        assert!(NationalId::suggestions("0000000000").is_empty());
+    }
+}
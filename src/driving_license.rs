@@ -0,0 +1,144 @@
+use alloc::format;
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::locale::LocalizedError;
+use crate::normalize::{digits, digits_to_string, is_separator, to_persian_digits, DigitsError};
+
+/// The 10-digit number (شماره گواهینامه) printed on Iranian smart driving licenses.
+///
+/// The number has no published check digit, so only its shape is validated.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct DrivingLicense(String);
+
+impl TryFrom<&str> for DrivingLicense {
+    type Error = DrivingLicenseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let digits: [u32; 10] = digits(value.chars().filter(|c| !is_separator(*c))).map_err(|err| match err {
+            DigitsError::Length(len) => DrivingLicenseError::InvalidLength(len),
+            DigitsError::NonDigit => DrivingLicenseError::NonDigitCharacter,
+        })?;
+
+        if digits.iter().all(|d| *d == 0) {
+            return Err(DrivingLicenseError::AllZeros);
+        }
+        Ok(DrivingLicense(digits_to_string(&digits)))
+    }
+}
+
+impl TryFrom<String> for DrivingLicense {
+    type Error = DrivingLicenseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        DrivingLicense::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for DrivingLicense {
+    type Error = DrivingLicenseError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        DrivingLicense::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<u64> for DrivingLicense {
+    type Error = DrivingLicenseError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        DrivingLicense::try_from(format!("{:010}", value))
+    }
+}
+
+impl FromStr for DrivingLicense {
+    type Err = DrivingLicenseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DrivingLicense::try_from(s)
+    }
+}
+
+impl DrivingLicense {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl AsRef<str> for DrivingLicense {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for DrivingLicense {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum DrivingLicenseError {
+    InvalidLength(usize),
+    NonDigitCharacter,
+    AllZeros,
+}
+
+impl Error for DrivingLicenseError {}
+
+impl Display for DrivingLicenseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DrivingLicenseError::InvalidLength(len) => {
+                write!(f, "invalid driving license number: expected 10 digits, got {}", len)
+            }
+            DrivingLicenseError::NonDigitCharacter => {
+                write!(f, "invalid driving license number: contains non-digit characters")
+            }
+            DrivingLicenseError::AllZeros => write!(f, "invalid driving license number: all digits are zero"),
+        }
+    }
+}
+
+impl LocalizedError for DrivingLicenseError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "شماره گواهینامه نامعتبر است: ")?;
+        match self {
+            DrivingLicenseError::InvalidLength(len) => write!(f, "باید ۱۰ رقم باشد، {} رقم وارد شده", len),
+            DrivingLicenseError::NonDigitCharacter => write!(f, "فقط باید شامل رقم باشد"),
+            DrivingLicenseError::AllZeros => write!(f, "همه ارقام صفر است"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::driving_license::{DrivingLicense, DrivingLicenseError};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_validate_driving_license() {
+        let license = DrivingLicense::try_from(" ۹۸۱۲ ۳۴۵۶۷۸ ").unwrap();
+        assert_eq!(license.as_str(), "9812345678");
+        assert_eq!(license.to_persian_digits(), "۹۸۱۲۳۴۵۶۷۸");
+        assert_eq!(DrivingLicense::try_from(12345678u64).unwrap().as_str(), "0012345678");
+    }
+
+    #[test]
+    fn test_invalid_driving_license() {
+        assert_eq!(DrivingLicense::try_from("981234567"), Err(DrivingLicenseError::InvalidLength(9)));
+        assert_eq!(DrivingLicense::try_from("98123456x8"), Err(DrivingLicenseError::NonDigitCharacter));
+        assert_eq!(DrivingLicense::try_from("0000000000"), Err(DrivingLicenseError::AllZeros));
+    }
+}
@@ -0,0 +1,193 @@
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::legal_national_id::{LegalNationalId, LegalNationalIdError};
+use crate::locale::LocalizedError;
+use crate::national_id::{NationalId, NationalIdError};
+use crate::normalize::{is_separator, normalize_digit, to_persian_digits};
+
+/// Which identifier an economic code is based on.
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum EconomicCodeKind {
+    /// An individual's 10-digit national id.
+    Individual,
+    /// A legal entity's 11-digit legal national id.
+    Legal,
+    /// A 12-digit code issued before the national-id based scheme.
+    Legacy,
+}
+
+/// Iranian economic (tax) code (کد اقتصادی) as printed on invoices.
+///
+/// Current codes are the holder's national id or legal national id and are checksum
+/// validated accordingly; legacy 12-digit codes have no published check digit and are
+/// only checked structurally.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct EconomicCode(String);
+
+impl TryFrom<&str> for EconomicCode {
+    type Error = EconomicCodeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value: String = value.chars()
+            .filter(|c| !is_separator(*c))
+            .map(normalize_digit)
+            .collect();
+
+        if !value.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(EconomicCodeError::NonDigitCharacter);
+        }
+
+        match value.len() {
+            10 => {
+                NationalId::parse_strict(&value).map_err(EconomicCodeError::NationalId)?;
+            }
+            11 => {
+                LegalNationalId::try_from(value.as_str()).map_err(EconomicCodeError::LegalNationalId)?;
+            }
+            12 => {
+                if value.bytes().all(|b| b == value.as_bytes()[0]) {
+                    return Err(EconomicCodeError::RepeatedDigits);
+                }
+            }
+            len => return Err(EconomicCodeError::InvalidLength(len)),
+        }
+        Ok(EconomicCode(value))
+    }
+}
+
+impl TryFrom<String> for EconomicCode {
+    type Error = EconomicCodeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        EconomicCode::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for EconomicCode {
+    type Error = EconomicCodeError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        EconomicCode::try_from(value.as_str())
+    }
+}
+
+impl FromStr for EconomicCode {
+    type Err = EconomicCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EconomicCode::try_from(s)
+    }
+}
+
+impl EconomicCode {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The code written with Persian digits.
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    pub fn kind(&self) -> EconomicCodeKind {
+        match self.0.len() {
+            10 => EconomicCodeKind::Individual,
+            11 => EconomicCodeKind::Legal,
+            _ => EconomicCodeKind::Legacy,
+        }
+    }
+}
+
+impl AsRef<str> for EconomicCode {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for EconomicCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum EconomicCodeError {
+    InvalidLength(usize),
+    NonDigitCharacter,
+    RepeatedDigits,
+    NationalId(NationalIdError),
+    LegalNationalId(LegalNationalIdError),
+}
+
+impl Error for EconomicCodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EconomicCodeError::NationalId(err) => Some(err),
+            EconomicCodeError::LegalNationalId(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl Display for EconomicCodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EconomicCodeError::InvalidLength(len) => {
+                write!(f, "invalid economic code: expected 10, 11 or 12 digits, got {}", len)
+            }
+            EconomicCodeError::NonDigitCharacter => write!(f, "invalid economic code: contains non-digit characters"),
+            EconomicCodeError::RepeatedDigits => write!(f, "invalid economic code: all digits are identical"),
+            EconomicCodeError::NationalId(_) => write!(f, "invalid economic code: the embedded national id is invalid"),
+            EconomicCodeError::LegalNationalId(_) => {
+                write!(f, "invalid economic code: the embedded legal national id is invalid")
+            }
+        }
+    }
+}
+
+impl LocalizedError for EconomicCodeError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "کد اقتصادی نامعتبر است: ")?;
+        match self {
+            EconomicCodeError::InvalidLength(len) => write!(f, "باید 10، 11 یا 12 رقم باشد، {} رقم وارد شده", len),
+            EconomicCodeError::NonDigitCharacter => write!(f, "فقط باید شامل رقم باشد"),
+            EconomicCodeError::RepeatedDigits => write!(f, "همه ارقام یکسان هستند"),
+            EconomicCodeError::NationalId(err) => err.fmt_persian(f),
+            EconomicCodeError::LegalNationalId(err) => err.fmt_persian(f),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::economic_code::{EconomicCode, EconomicCodeError, EconomicCodeKind};
+    use crate::legal_national_id::LegalNationalIdError;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_kinds() {
+        assert_eq!(EconomicCode::try_from("0040010007").unwrap().kind(), EconomicCodeKind::Individual);
+        assert_eq!(EconomicCode::try_from("۱۰۳۸۰۲۸۴۷۹۰").unwrap().kind(), EconomicCodeKind::Legal);
+        assert_eq!(EconomicCode::try_from("4111-1234-5678").unwrap().kind(), EconomicCodeKind::Legacy);
+    }
+
+    #[test]
+    fn test_invalid_economic_code() {
+        assert_eq!(EconomicCode::try_from("123456789"), Err(EconomicCodeError::InvalidLength(9)));
+        assert_eq!(EconomicCode::try_from("004001000a"), Err(EconomicCodeError::NonDigitCharacter));
+        assert_eq!(EconomicCode::try_from("333333333333"), Err(EconomicCodeError::RepeatedDigits));
+        assert!(matches!(EconomicCode::try_from("0040010008"), Err(EconomicCodeError::NationalId(_))));
+        assert_eq!(
+            EconomicCode::try_from("10380284791"),
+            Err(EconomicCodeError::LegalNationalId(LegalNationalIdError::InvalidCheckDigit { expected: 0, found: 1 }))
+        );
+    }
+}
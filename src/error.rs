@@ -0,0 +1,243 @@
+use core::error::Error as StdError;
+use core::fmt::{Display, Formatter};
+
+use crate::bank_card::BankCardError;
+use crate::birth_certificate::BirthCertificateError;
+use crate::driving_license::DrivingLicenseError;
+use crate::economic_code::EconomicCodeError;
+use crate::foreign_national_id::ForeignNationalIdError;
+use crate::identifier::IdentifierKind;
+use crate::isin::IsinError;
+use crate::landline_number::LandlineNumberError;
+use crate::legal_national_id::LegalNationalIdError;
+use crate::license_plate::LicensePlateError;
+use crate::locale::LocalizedError;
+use crate::mobile_number::MobileNumberError;
+use crate::national_card_serial::NationalCardSerialError;
+use crate::national_id::NationalIdError;
+use crate::passport_number::PassportNumberError;
+use crate::person_id::PersonIdError;
+use crate::postal_code::PostalCodeError;
+use crate::registration_number::RegistrationNumberError;
+use crate::sayad_id::SayadIdError;
+use crate::sheba::ShebaError;
+use crate::social_security::{InsuranceNumberError, WorkshopCodeError};
+
+/// Any error of this crate, for applications validating several identifier types.
+///
+/// Every per-type error converts into it with `?`. It displays which kind of identifier
+/// was rejected and exposes the per-type error, with the details, as its `source()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    NationalId(NationalIdError),
+    LegalNationalId(LegalNationalIdError),
+    ForeignNationalId(ForeignNationalIdError),
+    PersonId(PersonIdError),
+    EconomicCode(EconomicCodeError),
+    Sheba(ShebaError),
+    BankCard(BankCardError),
+    PostalCode(PostalCodeError),
+    MobileNumber(MobileNumberError),
+    LandlineNumber(LandlineNumberError),
+    Isin(IsinError),
+    LicensePlate(LicensePlateError),
+    RegistrationNumber(RegistrationNumberError),
+    SayadId(SayadIdError),
+    BirthCertificateNumber(BirthCertificateError),
+    InsuranceNumber(InsuranceNumberError),
+    WorkshopCode(WorkshopCodeError),
+    DrivingLicense(DrivingLicenseError),
+    PassportNumber(PassportNumberError),
+    NationalCardSerial(NationalCardSerialError),
+}
+
+impl Error {
+    /// The kind of identifier that was rejected; for a `PersonId`, the kind it was parsed as.
+    pub fn kind(&self) -> IdentifierKind {
+        match self {
+            Error::NationalId(_) | Error::PersonId(PersonIdError::Citizen(_)) => IdentifierKind::NationalId,
+            Error::LegalNationalId(_) => IdentifierKind::LegalNationalId,
+            Error::ForeignNationalId(_) | Error::PersonId(PersonIdError::Foreign(_)) => {
+                IdentifierKind::ForeignNationalId
+            }
+            Error::EconomicCode(_) => IdentifierKind::EconomicCode,
+            Error::Sheba(_) => IdentifierKind::Sheba,
+            Error::BankCard(_) => IdentifierKind::BankCard,
+            Error::PostalCode(_) => IdentifierKind::PostalCode,
+            Error::MobileNumber(_) => IdentifierKind::MobileNumber,
+            Error::LandlineNumber(_) => IdentifierKind::LandlineNumber,
+            Error::Isin(_) => IdentifierKind::Isin,
+            Error::LicensePlate(_) => IdentifierKind::LicensePlate,
+            Error::RegistrationNumber(_) => IdentifierKind::RegistrationNumber,
+            Error::SayadId(_) => IdentifierKind::SayadId,
+            Error::BirthCertificateNumber(_) => IdentifierKind::BirthCertificateNumber,
+            Error::InsuranceNumber(_) => IdentifierKind::InsuranceNumber,
+            Error::WorkshopCode(_) => IdentifierKind::WorkshopCode,
+            Error::DrivingLicense(_) => IdentifierKind::DrivingLicense,
+            Error::PassportNumber(_) => IdentifierKind::PassportNumber,
+            Error::NationalCardSerial(_) => IdentifierKind::NationalCardSerial,
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(match self {
+            Error::NationalId(err) => err,
+            Error::LegalNationalId(err) => err,
+            Error::ForeignNationalId(err) => err,
+            Error::PersonId(err) => err,
+            Error::EconomicCode(err) => err,
+            Error::Sheba(err) => err,
+            Error::BankCard(err) => err,
+            Error::PostalCode(err) => err,
+            Error::MobileNumber(err) => err,
+            Error::LandlineNumber(err) => err,
+            Error::Isin(err) => err,
+            Error::LicensePlate(err) => err,
+            Error::RegistrationNumber(err) => err,
+            Error::SayadId(err) => err,
+            Error::BirthCertificateNumber(err) => err,
+            Error::InsuranceNumber(err) => err,
+            Error::WorkshopCode(err) => err,
+            Error::DrivingLicense(err) => err,
+            Error::PassportNumber(err) => err,
+            Error::NationalCardSerial(err) => err,
+        })
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid")?;
+        self.kind().name().split('_').try_for_each(|word| write!(f, " {}", word))
+    }
+}
+
+/// The Persian message of the per-type error.
+impl LocalizedError for Error {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::NationalId(err) => err.fmt_persian(f),
+            Error::LegalNationalId(err) => err.fmt_persian(f),
+            Error::ForeignNationalId(err) => err.fmt_persian(f),
+            Error::PersonId(err) => err.fmt_persian(f),
+            Error::EconomicCode(err) => err.fmt_persian(f),
+            Error::Sheba(err) => err.fmt_persian(f),
+            Error::BankCard(err) => err.fmt_persian(f),
+            Error::PostalCode(err) => err.fmt_persian(f),
+            Error::MobileNumber(err) => err.fmt_persian(f),
+            Error::LandlineNumber(err) => err.fmt_persian(f),
+            Error::Isin(err) => err.fmt_persian(f),
+            Error::LicensePlate(err) => err.fmt_persian(f),
+            Error::RegistrationNumber(err) => err.fmt_persian(f),
+            Error::SayadId(err) => err.fmt_persian(f),
+            Error::BirthCertificateNumber(err) => err.fmt_persian(f),
+            Error::InsuranceNumber(err) => err.fmt_persian(f),
+            Error::WorkshopCode(err) => err.fmt_persian(f),
+            Error::DrivingLicense(err) => err.fmt_persian(f),
+            Error::PassportNumber(err) => err.fmt_persian(f),
+            Error::NationalCardSerial(err) => err.fmt_persian(f),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($($variant:ident($error:ident)),* $(,)?) => {
+        $(
+            impl From<$error> for Error {
+                fn from(err: $error) -> Self {
+                    Error::$variant(err)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(
+    NationalId(NationalIdError),
+    LegalNationalId(LegalNationalIdError),
+    ForeignNationalId(ForeignNationalIdError),
+    PersonId(PersonIdError),
+    EconomicCode(EconomicCodeError),
+    Sheba(ShebaError),
+    BankCard(BankCardError),
+    PostalCode(PostalCodeError),
+    MobileNumber(MobileNumberError),
+    LandlineNumber(LandlineNumberError),
+    Isin(IsinError),
+    LicensePlate(LicensePlateError),
+    RegistrationNumber(RegistrationNumberError),
+    SayadId(SayadIdError),
+    BirthCertificateNumber(BirthCertificateError),
+    InsuranceNumber(InsuranceNumberError),
+    WorkshopCode(WorkshopCodeError),
+    DrivingLicense(DrivingLicenseError),
+    PassportNumber(PassportNumberError),
+    NationalCardSerial(NationalCardSerialError),
+);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::economic_code::EconomicCode;
+    use crate::error::Error;
+    use crate::fields::FieldError;
+    use crate::identifier::IdentifierKind;
+    use crate::locale::{Locale, LocalizedError};
+    use crate::national_id::{NationalId, NationalIdError};
+    use crate::person_id::PersonId;
+    use crate::sheba::Sheba;
+    use std::convert::TryFrom;
+    use std::error::Error as _;
+
+    fn parse_form(id: &str, sheba: &str) -> Result<(NationalId, Sheba), Error> {
+        Ok((NationalId::try_from(id)?, Sheba::try_from(sheba)?))
+    }
+
+    #[test]
+    fn test_wraps_per_type_errors() {
+        let err = parse_form("0040010007", "IR06").unwrap_err();
+        assert_eq!(err.kind(), IdentifierKind::Sheba);
+        assert_eq!(err.to_string(), "invalid sheba");
+        assert_eq!(err.source().unwrap().to_string(), "invalid sheba number: expected 26 characters, got 4");
+
+        let err = parse_form("0040010008", "IR06").unwrap_err();
+        assert_eq!(err, Error::NationalId(NationalIdError::InvalidCheckDigit { expected: 7, found: 8 }));
+        assert_eq!(err.to_localized_string(Locale::Fa), "کد ملی نامعتبر است: رقم کنترل باید ۷ باشد، نه ۸");
+
+        let err = Error::from(PersonId::try_from("12345678901x").unwrap_err());
+        assert_eq!(err.kind(), IdentifierKind::ForeignNationalId);
+        assert_eq!(err.to_string(), "invalid foreign national id");
+    }
+
+    #[test]
+    fn test_source_chain_reaches_wrapped_id_errors() {
+        fn chain(err: &Error) -> Vec<String> {
+            let mut chain = vec![err.to_string()];
+            let mut source = err.source();
+            while let Some(inner) = source {
+                chain.push(inner.to_string());
+                source = inner.source();
+            }
+            chain
+        }
+
+        let err = Error::from(EconomicCode::try_from("0040010008").unwrap_err());
+        assert_eq!(chain(&err), vec![
+            "invalid economic code",
+            "invalid economic code: the embedded national id is invalid",
+            "invalid iranian national id number: check digit should be 7, found 8",
+        ]);
+
+        let err = Error::PersonId(PersonId::try_from("0040010008").unwrap_err());
+        assert_eq!(chain(&err), vec![
+            "invalid national id",
+            "invalid person id: not a valid national id",
+            "invalid iranian national id number: check digit should be 7, found 8",
+        ]);
+
+        let field = FieldError::Invalid(err);
+        assert_eq!(field.to_string(), "invalid iranian national id number: check digit should be 7, found 8");
+        assert!(field.source().is_none());
+    }
+}
@@ -0,0 +1,211 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::ops::Range;
+
+use crate::bank_card::BankCard;
+use crate::landline_number::LandlineNumber;
+use crate::mobile_number::MobileNumber;
+use crate::national_id::NationalId;
+use crate::normalize::normalize_digit;
+use crate::sheba::Sheba;
+
+/// Digit groups a single identifier may be written in, e.g. the seven groups of
+/// `IR06 2960 0000 0010 0324 2000 01`.
+const MAX_GROUPS: usize = 7;
+
+/// A maximal run of ASCII, Persian or Arabic-Indic digits.
+struct DigitRun {
+    bytes: Range<usize>,
+    digits: usize,
+}
+
+fn digit_runs(text: &str) -> Vec<DigitRun> {
+    let mut runs: Vec<DigitRun> = Vec::new();
+    for (i, c) in text.char_indices() {
+        if !normalize_digit(c).is_ascii_digit() {
+            continue;
+        }
+        match runs.last_mut() {
+            Some(run) if run.bytes.end == i => {
+                run.bytes.end = i + c.len_utf8();
+                run.digits += 1;
+            }
+            _ => runs.push(DigitRun { bytes: i..i + c.len_utf8(), digits: 1 }),
+        }
+    }
+    runs
+}
+
+/// Finds every valid national id in `text`, with its byte range.
+///
+/// Candidates are runs of exactly ten digits (Persian and Arabic-Indic digits included)
+/// or the grouped `xxx-xxxxxx-x` form; longer digit runs are not split.
+pub fn national_ids(text: &str) -> Vec<(Range<usize>, NationalId)> {
+    let runs = digit_runs(text);
+    let mut matches = Vec::new();
+
+    let mut i = 0;
+    while i < runs.len() {
+        let run = &runs[i];
+        if run.digits == 10 {
+            if let Ok(id) = NationalId::try_from(&text[run.bytes.clone()]) {
+                matches.push((run.bytes.clone(), id));
+            }
+        } else if let [first, middle, last] = runs.get(i..i + 3).unwrap_or(&[]) {
+            let grouped = (first.digits, middle.digits, last.digits) == (3, 6, 1)
+                && &text[first.bytes.end..middle.bytes.start] == "-"
+                && &text[middle.bytes.end..last.bytes.start] == "-";
+            let bytes = first.bytes.start..last.bytes.end;
+            if grouped {
+                if let Ok(id) = NationalId::parse_lenient(&text[bytes.clone()]) {
+                    matches.push((bytes, id));
+                    i += 3;
+                    continue;
+                }
+            }
+        }
+        i += 1;
+    }
+    matches
+}
+
+/// An identifier found by [`identifiers`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Extracted {
+    NationalId(NationalId),
+    Sheba(Sheba),
+    BankCard(BankCard),
+    MobileNumber(MobileNumber),
+    LandlineNumber(LandlineNumber),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match {
+    /// Byte range of the identifier in the text, including any `IR` or `+` prefix.
+    pub range: Range<usize>,
+    pub value: Extracted,
+}
+
+/// Finds national ids, Shebas, card numbers and mobile and landline numbers in `text`
+/// in one pass, in order of appearance.
+///
+/// Digit groups separated by a single space or dash are joined when the result is a
+/// valid identifier, preferring the longest one; national ids are matched as in
+/// [`national_ids`].
+pub fn identifiers(text: &str) -> Vec<Match> {
+    let runs = digit_runs(text);
+    let mut matches = Vec::new();
+
+    let mut i = 0;
+    while i < runs.len() {
+        let mut best = None;
+        for j in i..runs.len().min(i + MAX_GROUPS) {
+            if j > i && !matches!(&text[runs[j - 1].bytes.end..runs[j].bytes.start], " " | "-") {
+                break;
+            }
+            if let Some(found) = identify(text, &runs[i..=j]) {
+                best = Some((j, found));
+            }
+        }
+
+        match best {
+            Some((j, found)) => {
+                matches.push(found);
+                i = j + 1;
+            }
+            None => i += 1,
+        }
+    }
+    matches
+}
+
+/// Tries the identifier types whose length matches the digits in `runs`.
+fn identify(text: &str, runs: &[DigitRun]) -> Option<Match> {
+    let start = runs[0].bytes.start;
+    let end = runs[runs.len() - 1].bytes.end;
+    let before = &text[..start];
+    let digits: String = text[start..end].chars().map(normalize_digit).filter(char::is_ascii_digit).collect();
+    let plus = if before.ends_with('+') { start - 1 } else { start };
+
+    let (range, value) = match digits.len() {
+        10 => {
+            let grouped = runs.len() == 3 && !national_ids(&text[start..end]).is_empty();
+            if runs.len() != 1 && !grouped {
+                return None;
+            }
+            (start..end, Extracted::NationalId(NationalId::parse_lenient(&text[start..end]).ok()?))
+        }
+        24 if before.ends_with("IR") || before.ends_with("ir") => {
+            (start - 2..end, Extracted::Sheba(Sheba::try_from(&text[start - 2..end]).ok()?))
+        }
+        16 => (start..end, Extracted::BankCard(BankCard::try_from(&text[start..end]).ok()?)),
+        // Local `0…`, or the `98…`/`+98…` and `0098…` international forms.
+        11..=13 => {
+            let phone = &text[plus..end];
+            let value = match MobileNumber::try_from(phone) {
+                Ok(mobile) => Extracted::MobileNumber(mobile),
+                Err(_) => Extracted::LandlineNumber(LandlineNumber::try_from(phone).ok()?),
+            };
+            (plus..end, value)
+        }
+        _ => return None,
+    };
+    Some(Match { range, value })
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::bank_card::BankCard;
+    use crate::extract::{identifiers, national_ids, Extracted};
+    use crate::landline_number::LandlineNumber;
+    use crate::mobile_number::MobileNumber;
+    use crate::national_id::NationalId;
+    use crate::sheba::Sheba;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_finds_ids_with_byte_offsets() {
+        let text = "کد ملی ۰۰۴۰۰۱۰۰۰۷ و 0814659438، نه 0040010008 و نه 00400100071.";
+        let found = national_ids(text);
+        assert_eq!(found.len(), 2);
+
+        let (range, id) = &found[0];
+        assert_eq!(*id, NationalId::try_from("0040010007").unwrap());
+        assert_eq!(&text[range.clone()], "۰۰۴۰۰۱۰۰۰۷");
+        assert_eq!(&text[found[1].0.clone()], "0814659438");
+    }
+
+    #[test]
+    fn test_finds_grouped_form() {
+        let text = "id: 006-041825-7, phone: 021-123456-8";
+        let found = national_ids(text);
+        assert_eq!(found, vec![(4..16, NationalId::try_from("0060418257").unwrap())]);
+        assert!(national_ids("no ids here").is_empty());
+    }
+
+    #[test]
+    fn test_identifiers_of_every_type() {
+        let text = "شبا IR82 0540 1026 8002 0817 9090 02، کارت 6219-8610-3452-9007، \
+                    همراه +98 912 345 6789، تلفن 021-8888 1234، کد ملی 0040010007";
+        let found: Vec<(&str, Extracted)> = identifiers(text).into_iter()
+            .map(|m| (&text[m.range], m.value))
+            .collect();
+
+        assert_eq!(found, vec![
+            ("IR82 0540 1026 8002 0817 9090 02", Extracted::Sheba(Sheba::try_from("IR820540102680020817909002").unwrap())),
+            ("6219-8610-3452-9007", Extracted::BankCard(BankCard::try_from("6219861034529007").unwrap())),
+            ("+98 912 345 6789", Extracted::MobileNumber(MobileNumber::try_from("09123456789").unwrap())),
+            ("021-8888 1234", Extracted::LandlineNumber(LandlineNumber::try_from("02188881234").unwrap())),
+            ("0040010007", Extracted::NationalId(NationalId::try_from("0040010007").unwrap())),
+        ]);
+    }
+
+    #[test]
+    fn test_identifiers_skip_invalid_candidates() {
+        assert!(identifiers("card 6219-8610-3452-9008 and id 0040010008").is_empty());
+        let found = identifiers("0040010007 0814659438");
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[1].range, 11..21);
+    }
+}
@@ -0,0 +1,127 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hasher};
+#[cfg(feature = "std")]
+use std::collections::hash_map::RandomState;
+
+#[cfg(feature = "alloc")]
+use crate::national_id::NationalId;
+
+/// Reproducible batch of `count` valid national ids: the same seed always yields the same ids.
+#[cfg(feature = "alloc")]
+pub fn national_ids(seed: u64, count: usize) -> Vec<NationalId> {
+    let mut rng = Rng::new(seed);
+    (0..count).map(|_| NationalId::random(&mut rng)).collect()
+}
+
+/// Like [`national_ids`], drawing each id's province in proportion to its population
+/// (see [`NationalId::random_by_population`]).
+#[cfg(feature = "alloc")]
+pub fn national_ids_by_population(seed: u64, count: usize) -> Vec<NationalId> {
+    let mut rng = Rng::new(seed);
+    (0..count).map(|_| NationalId::random_by_population(&mut rng)).collect()
+}
+
+/// Small seedable pseudo-random generator (SplitMix64) used to produce test data.
+///
+/// It is reproducible across runs and platforms for a given seed, and is not
+/// suitable for anything security related.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    /// Seeds the generator from the process-wide random hasher keys.
+    #[cfg(feature = "std")]
+    pub fn from_entropy() -> Self {
+        Rng(RandomState::new().build_hasher().finish())
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..n`.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Rng::below called with n == 0");
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let v = self.next_u64();
+            if v < zone {
+                return v % n;
+            }
+        }
+    }
+
+    /// A uniformly chosen element of a non-empty slice.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    /// `prefix` followed by random ASCII digits up to `len` characters.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn digits_after(&mut self, prefix: &str, len: usize) -> String {
+        let mut value = String::from(prefix);
+        while value.len() < len {
+            value.push(char::from(b'0' + self.below(10) as u8));
+        }
+        value
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::fake::{national_ids, national_ids_by_population, Rng};
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let a: Vec<u64> = (0..5).scan(Rng::new(42), |rng, _| Some(rng.next_u64())).collect();
+        let b: Vec<u64> = (0..5).scan(Rng::new(42), |rng, _| Some(rng.next_u64())).collect();
+        assert_eq!(a, b);
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn test_national_ids_are_reproducible() {
+        let ids = national_ids(2024, 100);
+        assert_eq!(ids.len(), 100);
+        assert_eq!(ids, national_ids(2024, 100));
+        assert_ne!(ids, national_ids(2025, 100));
+        assert_eq!(national_ids(2024, 10)[..], ids[..10]);
+    }
+
+    #[test]
+    fn test_national_ids_by_population_are_reproducible() {
+        let ids = national_ids_by_population(2024, 100);
+        assert_eq!(ids, national_ids_by_population(2024, 100));
+        assert!(ids.iter().all(|id| id.issuance_location().is_some()));
+    }
+
+    #[test]
+    fn test_below_stays_in_range() {
+        let mut rng = Rng::new(7);
+        assert!((0..1000).all(|_| rng.below(10) < 10));
+        assert_eq!(rng.below(1), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "n == 0")]
+    fn test_below_zero_panics() {
+        Rng::new(7).below(0);
+    }
+}
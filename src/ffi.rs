@@ -0,0 +1,170 @@
+use std::convert::TryFrom;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+use crate::national_id::{NationalId, NationalIdError};
+use crate::sheba::{Sheba, ShebaError};
+
+/// The input is valid.
+pub const ININ_OK: c_int = 0;
+/// A null pointer was passed.
+pub const ININ_ERR_NULL: c_int = 1;
+/// The input is not valid UTF-8.
+pub const ININ_ERR_UTF8: c_int = 2;
+/// The input has the wrong number of digits or characters.
+pub const ININ_ERR_LENGTH: c_int = 3;
+/// The input contains a character that is not allowed.
+pub const ININ_ERR_CHARACTER: c_int = 4;
+/// The check digit or checksum does not match.
+pub const ININ_ERR_CHECKSUM: c_int = 5;
+/// The input is well formed but rejected for another reason, such as an all-zero id
+/// or a non-Iranian IBAN.
+pub const ININ_ERR_INVALID: c_int = 6;
+
+/// Reads a NUL-terminated UTF-8 string.
+///
+/// # Safety
+///
+/// `input` must be null or point to a NUL-terminated string that outlives `'a`.
+unsafe fn read<'a>(input: *const c_char) -> Result<&'a str, c_int> {
+    if input.is_null() {
+        return Err(ININ_ERR_NULL);
+    }
+    CStr::from_ptr(input).to_str().map_err(|_| ININ_ERR_UTF8)
+}
+
+fn national_id_code(error: &NationalIdError) -> c_int {
+    match error {
+        NationalIdError::InvalidLength(_) => ININ_ERR_LENGTH,
+        NationalIdError::NonDigitCharacter => ININ_ERR_CHARACTER,
+        NationalIdError::InvalidCheckDigit { .. } => ININ_ERR_CHECKSUM,
+        NationalIdError::AllZeros | NationalIdError::RepeatedDigits | NationalIdError::Denied => ININ_ERR_INVALID,
+    }
+}
+
+fn sheba_code(error: &ShebaError) -> c_int {
+    match error {
+        ShebaError::InvalidLength(_) => ININ_ERR_LENGTH,
+        ShebaError::NonDigitCharacter => ININ_ERR_CHARACTER,
+        ShebaError::InvalidChecksum => ININ_ERR_CHECKSUM,
+        ShebaError::InvalidCountryCode => ININ_ERR_INVALID,
+        // Only `Sheba::from_bban` reports these, and it is not exported.
+        ShebaError::InvalidBankCode(_) | ShebaError::AccountTooLong(_) => ININ_ERR_INVALID,
+    }
+}
+
+/// Validates a national id, returning `ININ_OK` or an `ININ_ERR_*` code.
+///
+/// # Safety
+///
+/// `input` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn inin_national_id_is_valid(input: *const c_char) -> c_int {
+    match read(input) {
+        Ok(value) => NationalId::try_from(value).map_or_else(|e| national_id_code(&e), |_| ININ_OK),
+        Err(code) => code,
+    }
+}
+
+/// Validates a Sheba (Iranian IBAN), returning `ININ_OK` or an `ININ_ERR_*` code.
+///
+/// # Safety
+///
+/// `input` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn inin_sheba_is_valid(input: *const c_char) -> c_int {
+    match read(input) {
+        Ok(value) => Sheba::try_from(value).map_or_else(|e| sheba_code(&e), |_| ININ_OK),
+        Err(code) => code,
+    }
+}
+
+/// Returns the canonical ten-digit form of a national id, or null if it is invalid.
+///
+/// The returned string is owned by the caller and must be released with [`inin_string_free`].
+///
+/// # Safety
+///
+/// `input` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn inin_national_id_normalize(input: *const c_char) -> *mut c_char {
+    match read(input).ok().and_then(|value| NationalId::try_from(value).ok()) {
+        Some(id) => CString::new(id.as_str()).map_or(ptr::null_mut(), CString::into_raw),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Releases a string returned by this library. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `value` must be null or a pointer returned by this library that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn inin_string_free(value: *mut c_char) {
+    if !value.is_null() {
+        drop(CString::from_raw(value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ffi::*;
+    use std::ffi::{CStr, CString};
+    use std::ptr;
+
+    #[test]
+    fn test_is_valid_error_codes() {
+        let valid = CString::new("0040010007").unwrap();
+        let wrong = CString::new("0040010008").unwrap();
+        let long = CString::new("00400100071").unwrap();
+        unsafe {
+            assert_eq!(inin_national_id_is_valid(valid.as_ptr()), ININ_OK);
+            assert_eq!(inin_national_id_is_valid(wrong.as_ptr()), ININ_ERR_CHECKSUM);
+            assert_eq!(inin_national_id_is_valid(long.as_ptr()), ININ_ERR_LENGTH);
+            assert_eq!(inin_national_id_is_valid(ptr::null()), ININ_ERR_NULL);
+            assert_eq!(inin_national_id_is_valid(b"\xff\0".as_ptr().cast()), ININ_ERR_UTF8);
+        }
+
+        let sheba = CString::new("IR062960000000100324200001").unwrap();
+        let foreign = CString::new("DE062960000000100324200001").unwrap();
+        unsafe {
+            assert_eq!(inin_sheba_is_valid(sheba.as_ptr()), ININ_OK);
+            assert_eq!(inin_sheba_is_valid(foreign.as_ptr()), ININ_ERR_INVALID);
+        }
+    }
+
+    #[test]
+    fn test_normalize_and_free() {
+        let input = CString::new("40010007").unwrap();
+        let invalid = CString::new("40010008").unwrap();
+        unsafe {
+            let normalized = inin_national_id_normalize(input.as_ptr());
+            assert_eq!(CStr::from_ptr(normalized).to_str(), Ok("0040010007"));
+            inin_string_free(normalized);
+
+            assert!(inin_national_id_normalize(invalid.as_ptr()).is_null());
+            inin_string_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_header_matches_exports() {
+        let header = include_str!("../include/inin.h");
+        let codes = [
+            ("ININ_OK", ININ_OK),
+            ("ININ_ERR_NULL", ININ_ERR_NULL),
+            ("ININ_ERR_UTF8", ININ_ERR_UTF8),
+            ("ININ_ERR_LENGTH", ININ_ERR_LENGTH),
+            ("ININ_ERR_CHARACTER", ININ_ERR_CHARACTER),
+            ("ININ_ERR_CHECKSUM", ININ_ERR_CHECKSUM),
+            ("ININ_ERR_INVALID", ININ_ERR_INVALID),
+        ];
+        for (name, code) in codes {
+            assert!(header.contains(&format!("#define {} {}\n", name, code)), "{} missing from inin.h", name);
+        }
+        for function in ["inin_national_id_is_valid(", "inin_sheba_is_valid(", "inin_national_id_normalize(", "inin_string_free("] {
+            assert!(header.contains(function), "{} missing from inin.h", function);
+        }
+    }
+}
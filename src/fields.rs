@@ -0,0 +1,137 @@
+use alloc::collections::BTreeMap;
+use core::error::Error as StdError;
+use core::fmt::{Display, Formatter};
+
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::jalali_date::JalaliDateError;
+use crate::locale::LocalizedError;
+
+/// Why one field of a form failed validation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldError {
+    Missing,
+    Invalid(Error),
+    InvalidDate(JalaliDateError),
+    /// A date, such as a birth date, that is after today.
+    FutureDate,
+    /// A valid value that contradicts the named field.
+    Mismatch(&'static str),
+}
+
+/// The message already holds the most detailed error of the wrapped chain, so there is
+/// no `source()`.
+impl StdError for FieldError {}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FieldError::Missing => write!(f, "required"),
+            FieldError::Invalid(err) => {
+                let mut inner: &dyn StdError = err;
+                while let Some(source) = inner.source() {
+                    inner = source;
+                }
+                write!(f, "{}", inner)
+            }
+            FieldError::InvalidDate(err) => write!(f, "{}", err),
+            FieldError::FutureDate => write!(f, "must not be in the future"),
+            FieldError::Mismatch(field) => write!(f, "does not match {}", field),
+        }
+    }
+}
+
+impl LocalizedError for FieldError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FieldError::Missing => write!(f, "الزامی است"),
+            FieldError::Invalid(err) => err.fmt_persian(f),
+            FieldError::InvalidDate(err) => err.fmt_persian(f),
+            FieldError::FutureDate => write!(f, "نباید در آینده باشد"),
+            FieldError::Mismatch(field) => write!(f, "با {} مطابقت ندارد", field),
+        }
+    }
+}
+
+/// The error of every invalid field of a form, keyed by field name; never empty when
+/// returned by a validator.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FieldErrors(BTreeMap<&'static str, FieldError>);
+
+impl FieldErrors {
+    pub fn get(&self, field: &str) -> Option<&FieldError> {
+        self.0.get(field)
+    }
+
+    /// Field names and errors, ordered by field name.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &FieldError)> {
+        self.0.iter().map(|(field, err)| (*field, err))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn insert(&mut self, field: &'static str, err: FieldError) {
+        self.0.insert(field, err);
+    }
+
+    /// Parses `value` as `T`, recording its error under `field` if it is invalid.
+    pub fn check_identifier<T>(&mut self, field: &'static str, value: &str) -> Option<T>
+    where
+        T: Identifier,
+        T::Error: Into<Error>,
+    {
+        self.check(field, Some(value), |value| T::parse(value).map_err(|err| FieldError::Invalid(err.into())))
+    }
+
+    /// Parses a field, recording a [`FieldError::Missing`] if there is no value or the
+    /// error returned by `parse`.
+    pub(crate) fn check<T>(
+        &mut self,
+        field: &'static str,
+        value: Option<&str>,
+        parse: impl FnOnce(&str) -> Result<T, FieldError>,
+    ) -> Option<T> {
+        let result = value.ok_or(FieldError::Missing).and_then(parse);
+        match result {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.insert(field, err);
+                None
+            }
+        }
+    }
+}
+
+impl StdError for FieldErrors {}
+
+/// `field: error` pairs separated by `; `.
+impl Display for FieldErrors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for (i, (field, err)) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}: {}", field, err)?;
+        }
+        Ok(())
+    }
+}
+
+impl LocalizedError for FieldErrors {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for (i, (field, err)) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, "؛ ")?;
+            }
+            write!(f, "{}: ", field)?;
+            err.fmt_persian(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::locale::LocalizedError;
+use crate::normalize::{is_separator, normalize_digit, to_persian_digits};
+
+/// Foreign nationals identification code (کد فراگیر اتباع خارجی), the 12-digit
+/// identifier issued to foreign residents instead of a national id.
+///
+/// The issuing authority does not publish a check-digit algorithm, so only the
+/// structure is validated: twelve digits, not all identical.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct ForeignNationalId(String);
+
+impl TryFrom<&str> for ForeignNationalId {
+    type Error = ForeignNationalIdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value: String = value.chars()
+            .filter(|c| !is_separator(*c))
+            .map(normalize_digit)
+            .collect();
+        let len = value.chars().count();
+        if len != 12 {
+            return Err(ForeignNationalIdError::InvalidLength(len));
+        }
+
+        if !value.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ForeignNationalIdError::NonDigitCharacter);
+        }
+
+        if value.bytes().all(|b| b == value.as_bytes()[0]) {
+            return Err(ForeignNationalIdError::RepeatedDigits);
+        }
+        Ok(ForeignNationalId(value))
+    }
+}
+
+impl TryFrom<String> for ForeignNationalId {
+    type Error = ForeignNationalIdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        ForeignNationalId::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for ForeignNationalId {
+    type Error = ForeignNationalIdError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        ForeignNationalId::try_from(value.as_str())
+    }
+}
+
+impl FromStr for ForeignNationalId {
+    type Err = ForeignNationalIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ForeignNationalId::try_from(s)
+    }
+}
+
+impl ForeignNationalId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The twelve digits written with Persian digits.
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl AsRef<str> for ForeignNationalId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for ForeignNationalId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum ForeignNationalIdError {
+    InvalidLength(usize),
+    NonDigitCharacter,
+    RepeatedDigits,
+}
+
+impl Error for ForeignNationalIdError {}
+
+impl Display for ForeignNationalIdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ForeignNationalIdError::InvalidLength(len) => {
+                write!(f, "invalid foreign national id: expected 12 digits, got {}", len)
+            }
+            ForeignNationalIdError::NonDigitCharacter => {
+                write!(f, "invalid foreign national id: contains non-digit characters")
+            }
+            ForeignNationalIdError::RepeatedDigits => {
+                write!(f, "invalid foreign national id: all digits are identical")
+            }
+        }
+    }
+}
+
+impl LocalizedError for ForeignNationalIdError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "کد فراگیر اتباع نامعتبر است: ")?;
+        match self {
+            ForeignNationalIdError::InvalidLength(len) => write!(f, "باید 12 رقم باشد، {} رقم وارد شده", len),
+            ForeignNationalIdError::NonDigitCharacter => write!(f, "فقط باید شامل رقم باشد"),
+            ForeignNationalIdError::RepeatedDigits => write!(f, "همه ارقام یکسان هستند"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::foreign_national_id::{ForeignNationalId, ForeignNationalIdError};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_validate_foreign_national_id() {
+        assert_eq!(ForeignNationalId::try_from("۱۲۳۴-۵۶۷۸-۹۰۱۲").unwrap().as_str(), "123456789012");
+        assert!("123456789012".parse::<ForeignNationalId>().is_ok());
+    }
+
+    #[test]
+    fn test_invalid_foreign_national_id() {
+        assert_eq!(ForeignNationalId::try_from("0040010007"), Err(ForeignNationalIdError::InvalidLength(10)));
+        assert_eq!(ForeignNationalId::try_from("12345678901a"), Err(ForeignNationalIdError::NonDigitCharacter));
+        assert_eq!(ForeignNationalId::try_from("111111111111"), Err(ForeignNationalIdError::RepeatedDigits));
+    }
+}
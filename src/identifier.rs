@@ -0,0 +1,339 @@
+use alloc::string::{String, ToString};
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+use crate::bank_card::{BankCard, BankCardError};
+use crate::birth_certificate::{BirthCertificateError, BirthCertificateNumber};
+use crate::driving_license::{DrivingLicense, DrivingLicenseError};
+use crate::economic_code::{EconomicCode, EconomicCodeError};
+use crate::foreign_national_id::{ForeignNationalId, ForeignNationalIdError};
+use crate::input_error::InputError;
+use crate::isin::{Isin, IsinError};
+use crate::landline_number::{LandlineNumber, LandlineNumberError};
+use crate::legal_national_id::{LegalNationalId, LegalNationalIdError};
+use crate::license_plate::{LicensePlate, LicensePlateError};
+use crate::mobile_number::{MobileNumber, MobileNumberError};
+use crate::national_card_serial::{NationalCardSerial, NationalCardSerialError};
+use crate::national_id::{NationalId, NationalIdError};
+use crate::normalize::{is_separator, normalize_digit};
+use crate::passport_number::{PassportNumber, PassportNumberError};
+use crate::person_id::{PersonId, PersonIdError};
+use crate::postal_code::{PostalCode, PostalCodeError};
+use crate::registration_number::{RegistrationNumber, RegistrationNumberError};
+use crate::sayad_id::{SayadId, SayadIdError};
+use crate::sheba::{Sheba, ShebaError};
+use crate::social_security::{InsuranceNumber, InsuranceNumberError, WorkshopCode, WorkshopCodeError};
+
+/// The type of an [`Identifier`].
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum IdentifierKind {
+    NationalId,
+    LegalNationalId,
+    ForeignNationalId,
+    EconomicCode,
+    Sheba,
+    BankCard,
+    PostalCode,
+    MobileNumber,
+    LandlineNumber,
+    Isin,
+    LicensePlate,
+    RegistrationNumber,
+    SayadId,
+    BirthCertificateNumber,
+    InsuranceNumber,
+    WorkshopCode,
+    DrivingLicense,
+    PassportNumber,
+    NationalCardSerial,
+}
+
+impl IdentifierKind {
+    /// Snake case name, e.g. `national_id`, for use in configuration and field declarations.
+    pub fn name(&self) -> &'static str {
+        match self {
+            IdentifierKind::NationalId => "national_id",
+            IdentifierKind::LegalNationalId => "legal_national_id",
+            IdentifierKind::ForeignNationalId => "foreign_national_id",
+            IdentifierKind::EconomicCode => "economic_code",
+            IdentifierKind::Sheba => "sheba",
+            IdentifierKind::BankCard => "bank_card",
+            IdentifierKind::PostalCode => "postal_code",
+            IdentifierKind::MobileNumber => "mobile_number",
+            IdentifierKind::LandlineNumber => "landline_number",
+            IdentifierKind::Isin => "isin",
+            IdentifierKind::LicensePlate => "license_plate",
+            IdentifierKind::RegistrationNumber => "registration_number",
+            IdentifierKind::SayadId => "sayad_id",
+            IdentifierKind::BirthCertificateNumber => "birth_certificate_number",
+            IdentifierKind::InsuranceNumber => "insurance_number",
+            IdentifierKind::WorkshopCode => "workshop_code",
+            IdentifierKind::DrivingLicense => "driving_license",
+            IdentifierKind::PassportNumber => "passport_number",
+            IdentifierKind::NationalCardSerial => "national_card_serial",
+        }
+    }
+}
+
+impl Display for IdentifierKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Operations shared by every validated identifier type, for code that handles any of them.
+///
+/// Some types have inherent methods of the same name (`EconomicCode::kind` returns an
+/// [`EconomicCodeKind`](crate::economic_code::EconomicCodeKind)); call the trait's through a generic
+/// bound or as `Identifier::kind(&value)`.
+pub trait Identifier: Sized + Display {
+    type Error: Error + 'static;
+
+    fn parse(value: &str) -> Result<Self, Self::Error>;
+
+    fn is_valid(value: &str) -> bool {
+        Self::parse(value).is_ok()
+    }
+
+    fn kind(&self) -> IdentifierKind;
+
+    /// The canonical form, with ASCII digits and no separators.
+    fn normalized(&self) -> String;
+
+    /// A form safe to show in logs and UIs, with most digits replaced by `*`.
+    fn masked(&self) -> String;
+
+    /// Whether `c` can appear in the input at all, used to locate the first invalid
+    /// character; by default digits and separators.
+    fn is_input_char(c: char) -> bool {
+        digit_or_separator(c)
+    }
+
+    /// Like [`Identifier::parse`], keeping the input and the byte index of its first
+    /// character rejected by [`Identifier::is_input_char`] in the error.
+    fn parse_with_input(value: &str) -> Result<Self, InputError<Self::Error>> {
+        Self::parse(value).map_err(|err| {
+            let position = value.char_indices().find(|(_, c)| !Self::is_input_char(*c)).map(|(i, _)| i);
+            InputError::new(err, value, position)
+        })
+    }
+}
+
+fn digit_or_separator(c: char) -> bool {
+    normalize_digit(c).is_ascii_digit() || is_separator(c)
+}
+
+fn phone_number_char(c: char) -> bool {
+    digit_or_separator(c) || matches!(c, '+' | '(' | ')')
+}
+
+fn alphanumeric_or_separator(c: char) -> bool {
+    c.is_alphanumeric() || is_separator(c)
+}
+
+/// Replaces all but the first `head` and last `tail` characters with `*`.
+fn mask(value: &str, head: usize, tail: usize) -> String {
+    let len = value.chars().count();
+    value.chars().enumerate()
+        .map(|(i, c)| if i < head || i + tail >= len { c } else { '*' })
+        .collect()
+}
+
+impl Identifier for NationalId {
+    type Error = NationalIdError;
+
+    fn parse(value: &str) -> Result<Self, Self::Error> {
+        NationalId::try_from(value)
+    }
+
+    fn kind(&self) -> IdentifierKind {
+        IdentifierKind::NationalId
+    }
+
+    fn normalized(&self) -> String {
+        self.as_str().to_string()
+    }
+
+    fn masked(&self) -> String {
+        NationalId::masked(self)
+    }
+
+    /// Only surrounding whitespace is trimmed; dashes are not accepted.
+    fn is_input_char(c: char) -> bool {
+        normalize_digit(c).is_ascii_digit() || c.is_whitespace()
+    }
+}
+
+impl Identifier for BankCard {
+    type Error = BankCardError;
+
+    fn parse(value: &str) -> Result<Self, Self::Error> {
+        BankCard::try_from(value)
+    }
+
+    fn kind(&self) -> IdentifierKind {
+        IdentifierKind::BankCard
+    }
+
+    fn normalized(&self) -> String {
+        self.as_str().to_string()
+    }
+
+    fn masked(&self) -> String {
+        BankCard::masked(self)
+    }
+}
+
+/// Implements [`Identifier`] for a string newtype, keeping `head` and `tail` characters
+/// unmasked and accepting the characters for which `chars` returns `true`.
+macro_rules! impl_identifier {
+    ($type:ident, $error:ident, head = $head:expr, tail = $tail:expr) => {
+        impl_identifier!($type, $error, head = $head, tail = $tail, chars = digit_or_separator);
+    };
+    ($type:ident, $error:ident, head = $head:expr, tail = $tail:expr, chars = $chars:expr) => {
+        impl Identifier for $type {
+            type Error = $error;
+
+            fn parse(value: &str) -> Result<Self, Self::Error> {
+                $type::try_from(value)
+            }
+
+            fn kind(&self) -> IdentifierKind {
+                IdentifierKind::$type
+            }
+
+            fn normalized(&self) -> String {
+                self.as_str().to_string()
+            }
+
+            fn masked(&self) -> String {
+                mask(self.as_str(), $head, $tail)
+            }
+
+            fn is_input_char(c: char) -> bool {
+                $chars(c)
+            }
+        }
+    };
+}
+
+impl_identifier!(LegalNationalId, LegalNationalIdError, head = 3, tail = 2);
+impl_identifier!(ForeignNationalId, ForeignNationalIdError, head = 3, tail = 2);
+impl_identifier!(EconomicCode, EconomicCodeError, head = 3, tail = 2);
+impl_identifier!(Sheba, ShebaError, head = 4, tail = 4, chars = alphanumeric_or_separator);
+impl_identifier!(PostalCode, PostalCodeError, head = 3, tail = 2);
+impl_identifier!(SayadId, SayadIdError, head = 4, tail = 4);
+impl_identifier!(BirthCertificateNumber, BirthCertificateError, head = 1, tail = 1);
+impl_identifier!(MobileNumber, MobileNumberError, head = 4, tail = 4, chars = phone_number_char);
+impl_identifier!(LandlineNumber, LandlineNumberError, head = 3, tail = 2, chars = phone_number_char);
+impl_identifier!(InsuranceNumber, InsuranceNumberError, head = 2, tail = 2);
+impl_identifier!(WorkshopCode, WorkshopCodeError, head = 4, tail = 2);
+impl_identifier!(DrivingLicense, DrivingLicenseError, head = 2, tail = 2);
+impl_identifier!(PassportNumber, PassportNumberError, head = 1, tail = 2, chars = alphanumeric_or_separator);
+impl_identifier!(NationalCardSerial, NationalCardSerialError, head = 2, tail = 2, chars = alphanumeric_or_separator);
+// Securities codes and company registration numbers are public, so nothing is hidden.
+impl_identifier!(Isin, IsinError, head = 12, tail = 0, chars = alphanumeric_or_separator);
+impl_identifier!(RegistrationNumber, RegistrationNumberError, head = 10, tail = 0);
+
+/// Plates are shown on the vehicle, so `masked` hides nothing.
+impl Identifier for LicensePlate {
+    type Error = LicensePlateError;
+
+    fn parse(value: &str) -> Result<Self, Self::Error> {
+        LicensePlate::try_from(value)
+    }
+
+    fn kind(&self) -> IdentifierKind {
+        IdentifierKind::LicensePlate
+    }
+
+    fn normalized(&self) -> String {
+        self.to_string()
+    }
+
+    fn masked(&self) -> String {
+        self.to_string()
+    }
+
+    fn is_input_char(c: char) -> bool {
+        alphanumeric_or_separator(c)
+    }
+}
+
+/// The kind of a `PersonId` is that of the id it holds.
+impl Identifier for PersonId {
+    type Error = PersonIdError;
+
+    fn parse(value: &str) -> Result<Self, Self::Error> {
+        PersonId::try_from(value)
+    }
+
+    fn kind(&self) -> IdentifierKind {
+        match self {
+            PersonId::Citizen(id) => id.kind(),
+            PersonId::Foreign(id) => id.kind(),
+        }
+    }
+
+    fn normalized(&self) -> String {
+        self.as_str().to_string()
+    }
+
+    fn masked(&self) -> String {
+        match self {
+            PersonId::Citizen(id) => id.masked(),
+            PersonId::Foreign(id) => id.masked(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::economic_code::{EconomicCode, EconomicCodeKind};
+    use crate::identifier::{Identifier, IdentifierKind};
+    use crate::isin::Isin;
+    use crate::mobile_number::MobileNumber;
+    use crate::national_id::NationalId;
+    use crate::person_id::PersonId;
+    use crate::sheba::Sheba;
+    use std::convert::TryFrom;
+
+    fn describe<T: Identifier>(value: &str) -> Option<(IdentifierKind, String, String)> {
+        let id = T::parse(value).ok()?;
+        Some((id.kind(), id.normalized(), id.masked()))
+    }
+
+    #[test]
+    fn test_generic_use() {
+        assert_eq!(
+            describe::<NationalId>(" ۴۰۰۱۰۰۰۷ "),
+            Some((IdentifierKind::NationalId, "0040010007".to_string(), "004*****07".to_string()))
+        );
+        assert_eq!(
+            describe::<Sheba>("IR062960000000100324200001"),
+            Some((IdentifierKind::Sheba, "IR062960000000100324200001".to_string(), "IR06******************0001".to_string()))
+        );
+        assert_eq!(
+            describe::<MobileNumber>("+98 912 345 6789"),
+            Some((IdentifierKind::MobileNumber, "09123456789".to_string(), "0912***6789".to_string()))
+        );
+        assert_eq!(describe::<Isin>("IRO1IKCO0008").unwrap().2, "IRO1IKCO0008");
+        assert_eq!(describe::<NationalId>("0040010008"), None);
+
+        assert!(NationalId::is_valid("0040010007"));
+        assert!(!<Sheba as Identifier>::is_valid("IR06"));
+    }
+
+    #[test]
+    fn test_kind() {
+        let code = EconomicCode::try_from("0040010007").unwrap();
+        assert_eq!(code.kind(), EconomicCodeKind::Individual);
+        assert_eq!(Identifier::kind(&code), IdentifierKind::EconomicCode);
+
+        let person = PersonId::parse("123456789012").unwrap();
+        assert_eq!(person.kind(), IdentifierKind::ForeignNationalId);
+        assert_eq!(person.masked(), "123*******12");
+        assert_eq!(IdentifierKind::LandlineNumber.to_string(), "landline_number");
+    }
+}
@@ -0,0 +1,150 @@
+use alloc::string::String;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+use crate::locale::LocalizedError;
+use crate::normalize::normalize_digit;
+
+/// Longest input kept by an [`InputError`], in characters.
+pub const MAX_INPUT_CHARS: usize = 32;
+
+/// A parse error together with the input that caused it, as returned by
+/// [`Identifier::parse_with_input`](crate::Identifier::parse_with_input).
+///
+/// `Display` masks every digit of the input with `*`, so the message can be logged
+/// without leaking the identifier while still showing the stray characters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputError<E> {
+    error: E,
+    input: String,
+    truncated: bool,
+    position: Option<usize>,
+}
+
+impl<E> InputError<E> {
+    /// Keeps the first [`MAX_INPUT_CHARS`] characters of `input`; `position` is the byte
+    /// index of the first invalid character in the whole input, if any.
+    pub fn new(error: E, input: &str, position: Option<usize>) -> Self {
+        let input_end = input.char_indices().nth(MAX_INPUT_CHARS).map_or(input.len(), |(i, _)| i);
+        InputError {
+            error,
+            input: String::from(&input[..input_end]),
+            truncated: input_end < input.len(),
+            position,
+        }
+    }
+
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    pub fn into_error(self) -> E {
+        self.error
+    }
+
+    /// The input, unmasked but cut to [`MAX_INPUT_CHARS`] characters.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Whether [`InputError::input`] was cut short.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Byte index of the first character the type never accepts, e.g. the `a` in
+    /// `004001000a`; `None` when every character is acceptable and the error is about
+    /// the length or checksum instead.
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// The character at [`InputError::position`].
+    pub fn character(&self) -> Option<char> {
+        self.input.get(self.position?..)?.chars().next()
+    }
+
+    fn fmt_input(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "\"")?;
+        for c in self.input.chars() {
+            let c = if normalize_digit(c).is_ascii_digit() { '*' } else { c };
+            write!(f, "{}", c.escape_debug())?;
+        }
+        if self.truncated {
+            write!(f, "…")?;
+        }
+        write!(f, "\"")
+    }
+}
+
+impl<E: Error + 'static> Error for InputError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// The error, then the masked input and position, e.g.
+/// `invalid iranian national id number: contains non-digit characters (input "*********a", byte 9)`.
+impl<E: Display> Display for InputError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} (input ", self.error)?;
+        self.fmt_input(f)?;
+        if let Some(position) = self.position {
+            write!(f, ", byte {}", position)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<E: LocalizedError> LocalizedError for InputError<E> {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.error.fmt_persian(f)?;
+        write!(f, " (ورودی ")?;
+        self.fmt_input(f)?;
+        if let Some(position) = self.position {
+            write!(f, "، بایت {}", position)?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::identifier::Identifier;
+    use crate::input_error::{InputError, MAX_INPUT_CHARS};
+    use crate::isin::Isin;
+    use crate::national_id::{NationalId, NationalIdError};
+    use crate::sheba::Sheba;
+
+    #[test]
+    fn test_points_at_invalid_character() {
+        let err = NationalId::parse_with_input("۰۰۴۰۰۱۰۰x۷").unwrap_err();
+        assert_eq!(err.error(), &NationalIdError::NonDigitCharacter);
+        assert_eq!(err.position(), Some(16));
+        assert_eq!(err.character(), Some('x'));
+        assert_eq!(err.input(), "۰۰۴۰۰۱۰۰x۷");
+        assert_eq!(NationalId::parse_with_input("004-001-0007").unwrap_err().position(), Some(3));
+
+        let err = NationalId::parse_with_input("004001000a").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid iranian national id number: contains non-digit characters (input \"*********a\", byte 9)"
+        );
+
+        let err = NationalId::parse_with_input("0040010008").unwrap_err();
+        assert_eq!(err.position(), None);
+        assert_eq!(err.to_string(), "invalid iranian national id number: check digit should be 7, found 8 (input \"**********\")");
+
+        assert_eq!(Sheba::parse_with_input("IR06 2960 0000 0010 0324 2000 0!").unwrap_err().position(), Some(31));
+        assert_eq!(Isin::parse_with_input("IRO1IKCO000_").unwrap_err().character(), Some('_'));
+    }
+
+    #[test]
+    fn test_truncates_long_input() {
+        let input = "1".repeat(100);
+        let err = InputError::new(NationalIdError::InvalidLength(100), &input, None);
+        assert_eq!(err.input().len(), MAX_INPUT_CHARS);
+        assert!(err.is_truncated());
+        assert!(err.to_string().ends_with("*…\")"));
+    }
+}
@@ -0,0 +1,170 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::bank_card::luhn;
+use crate::locale::LocalizedError;
+use crate::normalize::{normalize_digit, to_persian_digits};
+
+/// International Securities Identification Number of an Iranian instrument,
+/// e.g. `IRO1IKCO0008`: `IR`, a nine-character alphanumeric code and a check digit.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct Isin(String);
+
+impl TryFrom<&str> for Isin {
+    type Error = IsinError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value: String = value.chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| normalize_digit(c).to_ascii_uppercase())
+            .collect();
+        let len = value.chars().count();
+        if len != 12 {
+            return Err(IsinError::InvalidLength(len));
+        }
+
+        if !value.starts_with("IR") {
+            return Err(IsinError::InvalidCountryCode);
+        }
+
+        if !value.bytes().all(|b| b.is_ascii_alphanumeric()) || !value.as_bytes()[11].is_ascii_digit() {
+            return Err(IsinError::InvalidCharacter);
+        }
+
+        let found = value.as_bytes()[11] - b'0';
+        let expected = check_digit(&value[..11]);
+        if expected != found {
+            return Err(IsinError::InvalidCheckDigit { expected, found });
+        }
+        Ok(Isin(value))
+    }
+}
+
+/// Luhn check digit over the payload with letters expanded to two-digit numbers (A = 10 … Z = 35).
+fn check_digit(payload: &str) -> u8 {
+    let mut digits: Vec<u32> = Vec::with_capacity(24);
+    for n in payload.chars().map(|c| c.to_digit(36).unwrap()) {
+        if n >= 10 {
+            digits.push(n / 10);
+        }
+        digits.push(n % 10);
+    }
+    (0..10).find(|d| luhn(&[digits.as_slice(), &[*d]].concat())).unwrap() as u8
+}
+
+impl TryFrom<String> for Isin {
+    type Error = IsinError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Isin::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for Isin {
+    type Error = IsinError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        Isin::try_from(value.as_str())
+    }
+}
+
+impl FromStr for Isin {
+    type Err = IsinError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Isin::try_from(s)
+    }
+}
+
+impl Isin {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The ISIN with its digits in Persian; letters are kept as is.
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl AsRef<str> for Isin {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Isin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum IsinError {
+    InvalidLength(usize),
+    InvalidCountryCode,
+    InvalidCharacter,
+    InvalidCheckDigit { expected: u8, found: u8 },
+}
+
+impl Error for IsinError {}
+
+impl Display for IsinError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            IsinError::InvalidLength(len) => write!(f, "invalid isin: expected 12 characters, got {}", len),
+            IsinError::InvalidCountryCode => write!(f, "invalid isin: must start with IR"),
+            IsinError::InvalidCharacter => {
+                write!(f, "invalid isin: expected alphanumeric characters and a trailing digit")
+            }
+            IsinError::InvalidCheckDigit { expected, found } => {
+                write!(f, "invalid isin: check digit should be {}, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl LocalizedError for IsinError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "شناسه ISIN نامعتبر است: ")?;
+        match self {
+            IsinError::InvalidLength(len) => write!(f, "باید 12 نویسه باشد، {} نویسه وارد شده", len),
+            IsinError::InvalidCountryCode => write!(f, "باید با IR شروع شود"),
+            IsinError::InvalidCharacter => write!(f, "فقط حروف و ارقام لاتین مجاز است و نویسه آخر باید رقم باشد"),
+            IsinError::InvalidCheckDigit { expected, found } => {
+                write!(f, "رقم کنترل باید {} باشد، نه {}", expected, found)
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::isin::{Isin, IsinError};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_validate_isin() {
+        assert!(Isin::try_from("IRO1IKCO0008").is_ok());
+        assert!(Isin::try_from("IRO1MLLT0005").is_ok());
+        assert_eq!(Isin::try_from("iro1 fold 0009").unwrap().as_str(), "IRO1FOLD0009");
+        assert!("IRO1IKCO0008".parse::<Isin>().is_ok());
+    }
+
+    #[test]
+    fn test_invalid_isin() {
+        assert_eq!(Isin::try_from("IRO1IKCO000"), Err(IsinError::InvalidLength(11)));
+        assert_eq!(Isin::try_from("US0378331005"), Err(IsinError::InvalidCountryCode));
+        assert_eq!(Isin::try_from("IRO1IKCO000X"), Err(IsinError::InvalidCharacter));
+        assert_eq!(Isin::try_from("IRO1IKCO-008"), Err(IsinError::InvalidCharacter));
+        assert_eq!(Isin::try_from("IRO1IKCO0007"), Err(IsinError::InvalidCheckDigit { expected: 8, found: 7 }));
+    }
+}
@@ -0,0 +1,69 @@
+/// Days before each month in a common Gregorian year.
+const DAYS_BEFORE_MONTH: [i64; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+/// The Solar Hijri (Shamsi) `(year, month, day)` of a Gregorian date, valid for Gregorian years from 1600.
+pub(crate) fn from_gregorian(year: i64, month: u32, day: u32) -> (i64, u32, u32) {
+    let leap_year = if month > 2 { year + 1 } else { year };
+    let mut days = 355_666 + 365 * year + (leap_year + 3) / 4 - (leap_year + 99) / 100
+        + (leap_year + 399) / 400 + day as i64 + DAYS_BEFORE_MONTH[month as usize - 1];
+
+    let mut shamsi_year = -1595 + 33 * (days / 12053);
+    days %= 12053;
+    shamsi_year += 4 * (days / 1461);
+    days %= 1461;
+    if days > 365 {
+        shamsi_year += (days - 1) / 365;
+        days = (days - 1) % 365;
+    }
+
+    let (month, day) = if days < 186 {
+        (1 + days / 31, 1 + days % 31)
+    } else {
+        (7 + (days - 186) / 30, 1 + (days - 186) % 30)
+    };
+    (shamsi_year, month as u32, day as u32)
+}
+
+/// The Gregorian `(year, month, day)` `days` days after 1970-01-01.
+fn gregorian_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Today's Shamsi date in Iran Standard Time (UTC+3:30).
+pub(crate) fn today() -> (i64, u32, u32) {
+    let seconds = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64);
+    let (year, month, day) = gregorian_from_days((seconds + 3 * 3600 + 1800).div_euclid(86_400));
+    from_gregorian(year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::jalali::{from_gregorian, gregorian_from_days};
+
+    #[test]
+    fn test_from_gregorian() {
+        assert_eq!(from_gregorian(2024, 3, 20), (1403, 1, 1));
+        assert_eq!(from_gregorian(2024, 3, 19), (1402, 12, 29));
+        assert_eq!(from_gregorian(2025, 3, 20), (1403, 12, 30));
+        assert_eq!(from_gregorian(2026, 10, 14), (1405, 7, 22));
+        assert_eq!(from_gregorian(1970, 1, 1), (1348, 10, 11));
+    }
+
+    #[test]
+    fn test_gregorian_from_days() {
+        assert_eq!(gregorian_from_days(0), (1970, 1, 1));
+        assert_eq!(gregorian_from_days(19_802), (2024, 3, 20));
+        assert_eq!(gregorian_from_days(-1), (1969, 12, 31));
+    }
+}
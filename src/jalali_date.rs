@@ -0,0 +1,193 @@
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+#[cfg(feature = "std")]
+use crate::jalali;
+use crate::locale::LocalizedError;
+use crate::normalize::normalize_digit;
+
+/// A date in the Solar Hijri (Shamsi) calendar, e.g. a birth date on a registration form.
+#[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct JalaliDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl JalaliDate {
+    /// Checks that `year` has four digits, `month` is between 1 and 12 and `day` exists
+    /// in that month: the first six months have 31 days, the next five 30 and Esfand 29,
+    /// or 30 in leap years.
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self, JalaliDateError> {
+        if !(1000..=9999).contains(&year) {
+            return Err(JalaliDateError::InvalidYear(year));
+        }
+        if !(1..=12).contains(&month) {
+            return Err(JalaliDateError::InvalidMonth(month));
+        }
+        if !(1..=JalaliDate::days_in_month(year, month)).contains(&day) {
+            return Err(JalaliDateError::InvalidDay(day));
+        }
+        Ok(JalaliDate { year, month, day })
+    }
+
+    /// Whether Esfand of `year` has 30 days, following the 33-year cycle of eight leap
+    /// years used by the official calendar for the current era.
+    pub fn is_leap_year(year: u16) -> bool {
+        (25 * year as u32 + 11) % 33 < 8
+    }
+
+    /// The number of days of `month` in `year`, or 0 if `month` is not between 1 and 12.
+    pub fn days_in_month(year: u16, month: u8) -> u8 {
+        match month {
+            1..=6 => 31,
+            7..=11 => 30,
+            12 if JalaliDate::is_leap_year(year) => 30,
+            12 => 29,
+            _ => 0,
+        }
+    }
+
+    /// Today's date in Iran.
+    #[cfg(feature = "std")]
+    pub fn today() -> Self {
+        let (year, month, day) = jalali::today();
+        JalaliDate { year: year as u16, month: month as u8, day: day as u8 }
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+/// Parses `year/month/day` with a four-digit year, e.g. `1370/5/12` or `۱۳۷۰/۰۵/۱۲`;
+/// dashes are accepted as separators too.
+impl TryFrom<&str> for JalaliDate {
+    type Error = JalaliDateError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut parts = value.trim().split(['/', '-']);
+        let mut next = |min_len: usize, max_len: usize| {
+            let part = parts.next().ok_or(JalaliDateError::InvalidFormat)?;
+            let mut len = 0;
+            let mut number = 0u16;
+            for c in part.chars() {
+                let digit = normalize_digit(c).to_digit(10).ok_or(JalaliDateError::InvalidFormat)?;
+                len += 1;
+                if len > max_len {
+                    return Err(JalaliDateError::InvalidFormat);
+                }
+                number = number * 10 + digit as u16;
+            }
+            if len < min_len {
+                return Err(JalaliDateError::InvalidFormat);
+            }
+            Ok(number)
+        };
+        let (year, month, day) = (next(4, 4)?, next(1, 2)?, next(1, 2)?);
+        if parts.next().is_some() {
+            return Err(JalaliDateError::InvalidFormat);
+        }
+        JalaliDate::new(year, month as u8, day as u8)
+    }
+}
+
+impl FromStr for JalaliDate {
+    type Err = JalaliDateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        JalaliDate::try_from(s)
+    }
+}
+
+/// The zero-padded `yyyy/mm/dd` form, e.g. `1370/05/12`.
+impl Display for JalaliDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:04}/{:02}/{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum JalaliDateError {
+    InvalidFormat,
+    InvalidYear(u16),
+    InvalidMonth(u8),
+    InvalidDay(u8),
+}
+
+impl Error for JalaliDateError {}
+
+impl Display for JalaliDateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            JalaliDateError::InvalidFormat => write!(f, "invalid jalali date: expected year/month/day, e.g. 1370/05/12"),
+            JalaliDateError::InvalidYear(year) => write!(f, "invalid jalali date: expected a four-digit year, got {}", year),
+            JalaliDateError::InvalidMonth(month) => write!(f, "invalid jalali date: {} is not a month", month),
+            JalaliDateError::InvalidDay(day) => write!(f, "invalid jalali date: the month has no day {}", day),
+        }
+    }
+}
+
+impl LocalizedError for JalaliDateError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "تاریخ نامعتبر است: ")?;
+        match self {
+            JalaliDateError::InvalidFormat => write!(f, "باید به شکل سال/ماه/روز باشد، مثلاً 1370/05/12"),
+            JalaliDateError::InvalidYear(year) => write!(f, "سال باید چهاررقمی باشد، نه {}", year),
+            JalaliDateError::InvalidMonth(month) => write!(f, "{} ماه معتبری نیست", month),
+            JalaliDateError::InvalidDay(day) => write!(f, "این ماه روز {} ندارد", day),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::jalali_date::{JalaliDate, JalaliDateError};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_parse_jalali_date() {
+        let date = JalaliDate::try_from("1370/5/12").unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (1370, 5, 12));
+        assert_eq!(date.to_string(), "1370/05/12");
+        assert_eq!("1370-05-12".parse(), Ok(date));
+        assert!(JalaliDate::try_from("1370/05/12") < JalaliDate::try_from("1370/06/01"));
+        assert_eq!(JalaliDate::try_from(" ۱۳۷۰/۰۵/۱۲ "), Ok(date));
+        assert_eq!(JalaliDate::try_from("١٣٧٠-٥-١٢"), Ok(date));
+    }
+
+    #[test]
+    fn test_leap_year() {
+        assert!(JalaliDate::is_leap_year(1399));
+        assert!(JalaliDate::is_leap_year(1403));
+        assert!(JalaliDate::is_leap_year(1408));
+        assert!(!JalaliDate::is_leap_year(1402));
+        assert!(!JalaliDate::is_leap_year(1404));
+        assert_eq!(JalaliDate::days_in_month(1403, 12), 30);
+        assert_eq!(JalaliDate::days_in_month(1404, 12), 29);
+        assert!(JalaliDate::try_from("1403/12/30").is_ok());
+        assert_eq!(JalaliDate::try_from("1404/12/30"), Err(JalaliDateError::InvalidDay(30)));
+    }
+
+    #[test]
+    fn test_invalid_jalali_date() {
+        assert_eq!(JalaliDate::try_from("1370/13/01"), Err(JalaliDateError::InvalidMonth(13)));
+        assert_eq!(JalaliDate::try_from("1370/07/31"), Err(JalaliDateError::InvalidDay(31)));
+        assert_eq!(JalaliDate::try_from("1370/06/00"), Err(JalaliDateError::InvalidDay(0)));
+        assert_eq!(JalaliDate::try_from("70/05/12"), Err(JalaliDateError::InvalidFormat));
+        assert_eq!(JalaliDate::try_from("0999/05/12"), Err(JalaliDateError::InvalidYear(999)));
+        assert_eq!(JalaliDate::try_from("1370/O5/12"), Err(JalaliDateError::InvalidFormat));
+        assert_eq!(JalaliDate::try_from("1370/05"), Err(JalaliDateError::InvalidFormat));
+        assert_eq!(JalaliDate::try_from("1370/05/12/1"), Err(JalaliDateError::InvalidFormat));
+    }
+}
@@ -0,0 +1,77 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::iter::Peekable;
+use core::str::CharIndices;
+
+/// The (line, key, value) members of a JSON object with string values, or the line of
+/// the first syntax error; line numbers start at 1.
+pub(crate) fn string_object(text: &str) -> Result<Vec<(usize, String, String)>, usize> {
+    let mut chars = text.char_indices().peekable();
+    let mut members = Vec::new();
+
+    skip_whitespace(&mut chars);
+    expect(&mut chars, '{', text)?;
+    skip_whitespace(&mut chars);
+    if chars.peek().map(|(_, c)| *c) == Some('}') {
+        chars.next();
+    } else {
+        loop {
+            skip_whitespace(&mut chars);
+            let start = chars.peek().map_or(text.len(), |(i, _)| *i);
+            let key = string(&mut chars, text)?;
+            skip_whitespace(&mut chars);
+            expect(&mut chars, ':', text)?;
+            skip_whitespace(&mut chars);
+            let value = string(&mut chars, text)?;
+            members.push((line_of(text, start), key, value));
+
+            skip_whitespace(&mut chars);
+            match chars.next() {
+                Some((_, ',')) => continue,
+                Some((_, '}')) => break,
+                Some((i, _)) => return Err(line_of(text, i)),
+                None => return Err(line_of(text, text.len())),
+            }
+        }
+    }
+
+    skip_whitespace(&mut chars);
+    match chars.next() {
+        None => Ok(members),
+        Some((i, _)) => Err(line_of(text, i)),
+    }
+}
+
+fn line_of(text: &str, index: usize) -> usize {
+    text[..index].matches('\n').count() + 1
+}
+
+fn skip_whitespace(chars: &mut Peekable<CharIndices<'_>>) {
+    while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+}
+
+fn expect(chars: &mut Peekable<CharIndices<'_>>, expected: char, text: &str) -> Result<(), usize> {
+    match chars.next() {
+        Some((_, c)) if c == expected => Ok(()),
+        Some((i, _)) => Err(line_of(text, i)),
+        None => Err(line_of(text, text.len())),
+    }
+}
+
+/// A string literal; only the `\"`, `\\` and `\/` escapes are supported.
+fn string(chars: &mut Peekable<CharIndices<'_>>, text: &str) -> Result<String, usize> {
+    expect(chars, '"', text)?;
+    let mut value = String::new();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok(value),
+            '\\' => match chars.next() {
+                Some((_, c @ ('"' | '\\' | '/'))) => value.push(c),
+                _ => return Err(line_of(text, i)),
+            },
+            '\n' => return Err(line_of(text, i)),
+            c => value.push(c),
+        }
+    }
+    Err(line_of(text, text.len()))
+}
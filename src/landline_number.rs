@@ -0,0 +1,239 @@
+use alloc::format;
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::locale::LocalizedError;
+use crate::normalize::{is_separator, normalize_digit, to_persian_digits};
+use crate::province::Province;
+
+/// (area code, province, main city)
+const AREA_CODES: [(&str, Province, &str); 31] = [
+    ("011", Province::Mazandaran, "Sari"),
+    ("013", Province::Gilan, "Rasht"),
+    ("017", Province::Golestan, "Gorgan"),
+    ("021", Province::Tehran, "Tehran"),
+    ("023", Province::Semnan, "Semnan"),
+    ("024", Province::Zanjan, "Zanjan"),
+    ("025", Province::Qom, "Qom"),
+    ("026", Province::Alborz, "Karaj"),
+    ("028", Province::Qazvin, "Qazvin"),
+    ("031", Province::Isfahan, "Isfahan"),
+    ("034", Province::Kerman, "Kerman"),
+    ("035", Province::Yazd, "Yazd"),
+    ("038", Province::ChaharmahalAndBakhtiari, "Shahrekord"),
+    ("041", Province::EastAzerbaijan, "Tabriz"),
+    ("044", Province::WestAzerbaijan, "Urmia"),
+    ("045", Province::Ardabil, "Ardabil"),
+    ("051", Province::RazaviKhorasan, "Mashhad"),
+    ("054", Province::SistanAndBaluchestan, "Zahedan"),
+    ("056", Province::SouthKhorasan, "Birjand"),
+    ("058", Province::NorthKhorasan, "Bojnurd"),
+    ("061", Province::Khuzestan, "Ahvaz"),
+    ("066", Province::Lorestan, "Khorramabad"),
+    ("071", Province::Fars, "Shiraz"),
+    ("074", Province::KohgiluyehAndBoyerAhmad, "Yasuj"),
+    ("076", Province::Hormozgan, "Bandar Abbas"),
+    ("077", Province::Bushehr, "Bushehr"),
+    ("081", Province::Hamadan, "Hamadan"),
+    ("083", Province::Kermanshah, "Kermanshah"),
+    ("084", Province::Ilam, "Ilam"),
+    ("086", Province::Markazi, "Arak"),
+    ("087", Province::Kurdistan, "Sanandaj"),
+];
+
+/// Iranian fixed-line number, stored in the local `0AAxxxxxxxx` form
+/// (three-digit area code followed by an eight-digit subscriber number).
+///
+/// Accepts the local form and `+98`/`0098` international forms, with separators and Persian digits.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct LandlineNumber(String);
+
+impl TryFrom<&str> for LandlineNumber {
+    type Error = LandlineNumberError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value: String = value.chars()
+            .filter(|c| !is_separator(*c) && !matches!(c, '(' | ')'))
+            .map(normalize_digit)
+            .collect();
+
+        let value = match value.strip_prefix("+98").or_else(|| value.strip_prefix("0098")) {
+            Some(rest) => format!("0{}", rest),
+            None => value,
+        };
+
+        if !value.chars().all(|c| c.is_ascii_digit()) {
+            return Err(LandlineNumberError::NonDigitCharacter);
+        }
+
+        if value.len() != 11 {
+            return Err(LandlineNumberError::InvalidLength(value.len()));
+        }
+
+        if area(&value[..3]).is_none() {
+            return Err(LandlineNumberError::UnknownAreaCode);
+        }
+        Ok(LandlineNumber(value))
+    }
+}
+
+fn area(code: &str) -> Option<&'static (&'static str, Province, &'static str)> {
+    AREA_CODES.iter().find(|(c, _, _)| *c == code)
+}
+
+impl TryFrom<String> for LandlineNumber {
+    type Error = LandlineNumberError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        LandlineNumber::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for LandlineNumber {
+    type Error = LandlineNumberError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        LandlineNumber::try_from(value.as_str())
+    }
+}
+
+impl FromStr for LandlineNumber {
+    type Err = LandlineNumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LandlineNumber::try_from(s)
+    }
+}
+
+impl LandlineNumber {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The local form, area code included, written with Persian digits.
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    /// Local (trunk-prefixed) form, e.g. `02112345678`.
+    pub fn to_local(&self) -> String {
+        self.0.clone()
+    }
+
+    /// Canonical E.164 form, e.g. `+982112345678`.
+    pub fn to_e164(&self) -> String {
+        format!("+98{}", &self.0[1..])
+    }
+
+    /// The three-digit area code, including the leading zero (e.g. `021`).
+    pub fn area_code(&self) -> &str {
+        &self.0[..3]
+    }
+
+    /// The eight-digit subscriber number following the area code.
+    pub fn subscriber_number(&self) -> &str {
+        &self.0[3..]
+    }
+
+    pub fn province(&self) -> Province {
+        area(self.area_code()).unwrap().1
+    }
+
+    /// The main city served by the area code.
+    pub fn city(&self) -> &'static str {
+        area(self.area_code()).unwrap().2
+    }
+}
+
+impl AsRef<str> for LandlineNumber {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for LandlineNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum LandlineNumberError {
+    InvalidLength(usize),
+    NonDigitCharacter,
+    UnknownAreaCode,
+}
+
+impl Error for LandlineNumberError {}
+
+impl Display for LandlineNumberError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LandlineNumberError::InvalidLength(len) => {
+                write!(f, "invalid landline number: expected 11 digits in local form, got {}", len)
+            }
+            LandlineNumberError::NonDigitCharacter => {
+                write!(f, "invalid landline number: contains non-digit characters")
+            }
+            LandlineNumberError::UnknownAreaCode => write!(f, "invalid landline number: unknown area code"),
+        }
+    }
+}
+
+impl LocalizedError for LandlineNumberError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "شماره تلفن ثابت نامعتبر است: ")?;
+        match self {
+            LandlineNumberError::InvalidLength(len) => write!(f, "باید 11 رقم باشد، {} رقم وارد شده", len),
+            LandlineNumberError::NonDigitCharacter => write!(f, "فقط باید شامل رقم باشد"),
+            LandlineNumberError::UnknownAreaCode => write!(f, "پیش‌شماره ناشناخته است"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::landline_number::{LandlineNumber, LandlineNumberError};
+    use crate::province::Province;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_accepted_formats() {
+        for input in &["02112345678", "+982112345678", "00982112345678", "021-1234 5678", "۰۲۱۱۲۳۴۵۶۷۸"] {
+            assert_eq!(LandlineNumber::try_from(*input).unwrap().as_str(), "02112345678", "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_area_lookup() {
+        let number = LandlineNumber::try_from("05138123456").unwrap();
+        assert_eq!(number.area_code(), "051");
+        assert_eq!(number.subscriber_number(), "38123456");
+        assert_eq!(number.province(), Province::RazaviKhorasan);
+        assert_eq!(number.city(), "Mashhad");
+    }
+
+    #[test]
+    fn test_local_and_e164_forms() {
+        let number = LandlineNumber::try_from("(021) 1234-5678").unwrap();
+        assert_eq!(number.to_local(), "02112345678");
+        assert_eq!(number.to_e164(), "+982112345678");
+        assert_eq!(LandlineNumber::try_from(number.to_e164()), LandlineNumber::try_from(number.to_local()));
+        assert_eq!(number, LandlineNumber::try_from("02112345678").unwrap());
+    }
+
+    #[test]
+    fn test_invalid_landline_number() {
+        assert_eq!(LandlineNumber::try_from("0211234567"), Err(LandlineNumberError::InvalidLength(10)));
+        assert_eq!(LandlineNumber::try_from("0211234567a"), Err(LandlineNumberError::NonDigitCharacter));
+        assert_eq!(LandlineNumber::try_from("02212345678"), Err(LandlineNumberError::UnknownAreaCode));
+        assert_eq!(LandlineNumber::try_from("09123456789"), Err(LandlineNumberError::UnknownAreaCode));
+    }
+}
@@ -0,0 +1,204 @@
+use alloc::string::{String, ToString};
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::locale::LocalizedError;
+use crate::normalize::{digits, digits_to_string, to_persian_digits, DigitsError};
+
+const WEIGHTS: [u32; 10] = [29, 27, 23, 19, 17, 29, 27, 23, 19, 17];
+
+/// Iranian legal-entity national id (شناسه ملی), the 11-digit identifier of companies and institutions.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct LegalNationalId(String);
+
+impl TryFrom<&str> for LegalNationalId {
+    type Error = LegalNationalIdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let digits: [u32; 11] = digits(value.trim().chars()).map_err(digits_error)?;
+
+        if digits[3..9].iter().all(|d| *d == 0) {
+            return Err(LegalNationalIdError::ZeroSequence);
+        }
+
+        let expected = control_digit(&digits[..10]);
+        let control_digit = digits[10];
+        if expected == control_digit {
+            return Ok(LegalNationalId(digits_to_string(&digits)));
+        }
+        Err(LegalNationalIdError::InvalidCheckDigit { expected: expected as u8, found: control_digit as u8 })
+    }
+}
+
+fn digits_error(err: DigitsError) -> LegalNationalIdError {
+    match err {
+        DigitsError::Length(len) => LegalNationalIdError::InvalidLength(len),
+        DigitsError::NonDigit => LegalNationalIdError::NonDigitCharacter,
+    }
+}
+
+/// The control digit for the first ten digits of an id.
+fn control_digit(digits: &[u32]) -> u32 {
+    let decimal = digits[9] + 2;
+    let sum: u32 = (0..10).map(|i| (digits[i] + decimal) * WEIGHTS[i]).sum();
+    match sum % 11 {
+        10 => 0,
+        rem => rem,
+    }
+}
+
+impl TryFrom<String> for LegalNationalId {
+    type Error = LegalNationalIdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        LegalNationalId::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for LegalNationalId {
+    type Error = LegalNationalIdError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        LegalNationalId::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<u64> for LegalNationalId {
+    type Error = LegalNationalIdError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        LegalNationalId::try_from(value.to_string().as_str())
+    }
+}
+
+impl FromStr for LegalNationalId {
+    type Err = LegalNationalIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LegalNationalId::try_from(s)
+    }
+}
+
+impl LegalNationalId {
+    /// Appends the control digit to the first ten digits of an id.
+    pub fn complete(first_ten: &str) -> Result<Self, LegalNationalIdError> {
+        let first_ten: [u32; 10] = digits(first_ten.trim().chars()).map_err(digits_error)?;
+        let mut digits = [0; 11];
+        digits[..10].copy_from_slice(&first_ten);
+        digits[10] = control_digit(&first_ten);
+        LegalNationalId::try_from(digits_to_string(&digits).as_str())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The id written with Persian digits, e.g. `۱۰۳۸۰۲۸۴۷۹۰`.
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl AsRef<str> for LegalNationalId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for LegalNationalId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum LegalNationalIdError {
+    InvalidLength(usize),
+    NonDigitCharacter,
+    ZeroSequence,
+    InvalidCheckDigit { expected: u8, found: u8 },
+}
+
+impl Error for LegalNationalIdError {}
+
+impl Display for LegalNationalIdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LegalNationalIdError::InvalidLength(len) => {
+                write!(f, "invalid iranian legal national id: expected 11 digits, got {}", len)
+            }
+            LegalNationalIdError::NonDigitCharacter => {
+                write!(f, "invalid iranian legal national id: contains non-digit characters")
+            }
+            LegalNationalIdError::ZeroSequence => {
+                write!(f, "invalid iranian legal national id: digits 4 to 9 are all zero")
+            }
+            LegalNationalIdError::InvalidCheckDigit { expected, found } => {
+                write!(f, "invalid iranian legal national id: check digit should be {}, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl LocalizedError for LegalNationalIdError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "شناسه ملی نامعتبر است: ")?;
+        match self {
+            LegalNationalIdError::InvalidLength(len) => write!(f, "باید 11 رقم باشد، {} رقم وارد شده", len),
+            LegalNationalIdError::NonDigitCharacter => write!(f, "فقط باید شامل رقم باشد"),
+            LegalNationalIdError::ZeroSequence => write!(f, "ارقام 4 تا 9 همه صفر هستند"),
+            LegalNationalIdError::InvalidCheckDigit { expected, found } => {
+                write!(f, "رقم کنترل باید {} باشد، نه {}", expected, found)
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::legal_national_id::{LegalNationalId, LegalNationalIdError};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_validate_legal_national_id() {
+        assert!(LegalNationalId::try_from("10380284790").is_ok());
+        assert!(LegalNationalId::try_from(" ۱۰۳۸۰۲۸۴۷۹۰ ").is_ok());
+        assert!("10380284790".parse::<LegalNationalId>().is_ok());
+        assert!(LegalNationalId::try_from(10380284790u64).is_ok());
+        assert!(LegalNationalId::try_from(String::from("10380284790")).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_legal_national_id() {
+        assert_eq!(LegalNationalId::try_from("1038028479"), Err(LegalNationalIdError::InvalidLength(10)));
+        assert_eq!(LegalNationalId::try_from("1038028479a"), Err(LegalNationalIdError::NonDigitCharacter));
+        assert_eq!(LegalNationalId::try_from("10300000000"), Err(LegalNationalIdError::ZeroSequence));
+        assert_eq!(
+            LegalNationalId::try_from("10380284791"),
+            Err(LegalNationalIdError::InvalidCheckDigit { expected: 0, found: 1 })
+        );
+    }
+
+    #[test]
+    fn test_complete() {
+        assert_eq!(LegalNationalId::complete("1038028479"), LegalNationalId::try_from("10380284790"));
+        assert_eq!(LegalNationalId::complete("۱۰۳۸۰۲۸۴۷۹").unwrap().as_str(), "10380284790");
+        assert_eq!(LegalNationalId::complete("103802847"), Err(LegalNationalIdError::InvalidLength(9)));
+        assert_eq!(LegalNationalId::complete("103802847x"), Err(LegalNationalIdError::NonDigitCharacter));
+        assert_eq!(LegalNationalId::complete("1030000009"), Err(LegalNationalIdError::ZeroSequence));
+    }
+
+    #[test]
+    fn test_display_and_accessors() {
+        let id = LegalNationalId::try_from("10380284790").unwrap();
+        assert_eq!(id.to_string(), "10380284790");
+        assert_eq!(id.as_str(), "10380284790");
+        assert_eq!(id.into_inner(), String::from("10380284790"));
+    }
+}
@@ -1,85 +1,175 @@
-use std::convert::TryFrom;
-use std::error::Error;
-use std::fmt::{Display, Formatter};
-use std::ops::Deref;
-
-#[derive(PartialOrd, PartialEq, Debug)]
-pub struct NationalId(String);
-
-impl TryFrom<&str> for NationalId {
-    type Error = NationalIdError;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let value = format!("{:0>10}", value.trim());
-        let digits: Vec<u32> = value.chars().filter_map(|c| c.to_digit(10)).collect();
-
-        if digits.len() != 10 {
-            return Err(NationalIdError);
-        }
-
-        let sum: u32 = (0..9).map(|i| { digits[i] * (10 - i) as u32 }).sum();
-        if sum == 0 { return Err(NationalIdError); }
-        let control_digit = *digits.last().unwrap();
-
-        let rem = sum % 11;
-        if (rem < 2 && rem == control_digit) || (rem >= 2 && rem + control_digit == 11) {
-            return Ok(NationalId(value));
-        }
-        Err(NationalIdError)
-    }
-}
-
-impl Deref for NationalId {
-    type Target = String;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-#[derive(Debug, Clone, PartialOrd, PartialEq)]
-pub struct NationalIdError;
-
-impl Error for NationalIdError {}
-
-impl Display for NationalIdError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "invalid iranian national id number")
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::{NationalId, NationalIdError};
-    use std::convert::{TryFrom, TryInto};
-
-    #[test]
-    fn test_length_of_code_should_pad_to_10_digit() {
-        assert_eq!(NationalId::try_from("0451726707"), Ok(NationalId(String::from("0451726707"))));
-    }
-
-    #[test]
-    fn test_invalid_input() {
-        assert!(NationalId::try_from("").is_err());
-        assert!(NationalId::try_from("123").is_err());
-        assert!(NationalId::try_from("123456ab").is_err());
-        assert!(NationalId::try_from("12345678ab").is_err());
-        assert!(NationalId::try_from("a814659438").is_err());
-    }
-
-    #[test]
-    fn test_should_deref_to_string() {
-        let code = "0814659438";
-        let result: Result<NationalId, _> = code.try_into();
-        assert_eq!(code, *result.unwrap())
-    }
-
-    #[test]
-    fn test_validate_national_id() {
-        let ni: Result<NationalId, NationalIdError> = "0040010007".try_into();
-        assert!(ni.is_ok());
-
-        let ni: Result<NationalId, NationalIdError> = "0814659438".try_into();
-        assert!(ni.is_ok());
-    }
-}
\ No newline at end of file
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod bank;
+#[cfg(feature = "alloc")]
+pub mod bank_account;
+#[cfg(feature = "alloc")]
+pub mod bank_card;
+#[cfg(feature = "std")]
+pub mod batch;
+#[cfg(feature = "alloc")]
+pub mod bin_table;
+#[cfg(feature = "alloc")]
+pub mod birth_certificate;
+#[cfg(feature = "alloc")]
+pub mod company;
+#[cfg(feature = "std")]
+pub mod csv;
+#[cfg(feature = "alloc")]
+pub mod diagnostics;
+#[cfg(feature = "alloc")]
+pub mod driving_license;
+#[cfg(feature = "alloc")]
+pub mod economic_code;
+#[cfg(feature = "alloc")]
+pub mod error;
+#[cfg(feature = "alloc")]
+pub mod extract;
+pub mod fake;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "alloc")]
+pub mod fields;
+#[cfg(feature = "alloc")]
+pub mod foreign_national_id;
+#[cfg(feature = "alloc")]
+pub mod identifier;
+#[cfg(feature = "alloc")]
+pub mod input_error;
+#[cfg(feature = "alloc")]
+pub mod isin;
+#[cfg(feature = "std")]
+mod jalali;
+pub mod jalali_date;
+#[cfg(feature = "alloc")]
+mod json;
+#[cfg(feature = "alloc")]
+pub mod landline_number;
+#[cfg(feature = "alloc")]
+pub mod legal_national_id;
+#[cfg(feature = "alloc")]
+pub mod license_plate;
+pub mod locale;
+#[cfg(feature = "alloc")]
+pub mod mobile_number;
+#[cfg(feature = "alloc")]
+pub mod national_card_serial;
+pub mod national_id;
+#[cfg(feature = "std")]
+pub mod national_id_set;
+mod normalize;
+#[cfg(feature = "alloc")]
+pub mod ocr;
+#[cfg(feature = "alloc")]
+pub mod operator_table;
+#[cfg(feature = "alloc")]
+pub mod passport_number;
+#[cfg(feature = "alloc")]
+pub mod payment_card;
+#[cfg(feature = "alloc")]
+pub mod person;
+#[cfg(feature = "alloc")]
+pub mod person_id;
+#[cfg(feature = "alloc")]
+pub mod postal_code;
+pub mod prefix_table;
+pub mod province;
+pub mod pseudonym;
+#[cfg(feature = "alloc")]
+pub mod registration_number;
+#[cfg(feature = "alloc")]
+pub mod registry;
+#[cfg(feature = "alloc")]
+pub mod sayad_id;
+mod sha256;
+#[cfg(feature = "alloc")]
+pub mod sheba;
+#[cfg(feature = "alloc")]
+pub mod social_security;
+#[cfg(feature = "alloc")]
+pub mod unverified;
+#[cfg(feature = "alloc")]
+pub mod validate;
+
+pub use bank::Bank;
+#[cfg(feature = "alloc")]
+pub use bank_account::{BankAccount, BankAccountError};
+#[cfg(feature = "alloc")]
+pub use bank_card::{BankCard, BankCardError};
+#[cfg(feature = "std")]
+pub use batch::{AuditEntry, AuditSummary, NationalIdIteratorExt};
+#[cfg(feature = "alloc")]
+pub use bin_table::{BinTable, BinTableError};
+#[cfg(feature = "alloc")]
+pub use birth_certificate::{BirthCertificateError, BirthCertificateNumber, BirthCertificateSeries};
+#[cfg(feature = "alloc")]
+pub use company::{Company, CompanyBuilder};
+#[cfg(feature = "alloc")]
+pub use diagnostics::{ChecksumExplanation, Issue, ValidationReport};
+#[cfg(feature = "alloc")]
+pub use driving_license::{DrivingLicense, DrivingLicenseError};
+#[cfg(feature = "alloc")]
+pub use economic_code::{EconomicCode, EconomicCodeError, EconomicCodeKind};
+#[cfg(feature = "alloc")]
+pub use error::Error;
+#[cfg(feature = "alloc")]
+pub use fields::{FieldError, FieldErrors};
+#[cfg(feature = "alloc")]
+pub use foreign_national_id::{ForeignNationalId, ForeignNationalIdError};
+#[cfg(feature = "alloc")]
+pub use identifier::{Identifier, IdentifierKind};
+#[cfg(feature = "alloc")]
+pub use input_error::InputError;
+#[cfg(feature = "alloc")]
+pub use isin::{Isin, IsinError};
+pub use jalali_date::{JalaliDate, JalaliDateError};
+#[cfg(feature = "alloc")]
+pub use landline_number::{LandlineNumber, LandlineNumberError};
+#[cfg(feature = "alloc")]
+pub use legal_national_id::{LegalNationalId, LegalNationalIdError};
+#[cfg(feature = "alloc")]
+pub use license_plate::{FreeZone, FreeZonePlate, LicensePlate, LicensePlateError, MotorcyclePlate, Plate, PlateKind};
+pub use locale::{Locale, LocalizedError};
+#[cfg(feature = "alloc")]
+pub use mobile_number::{MobileNumber, MobileNumberError, Operator};
+#[cfg(feature = "alloc")]
+pub use national_card_serial::{NationalCardSerial, NationalCardSerialError};
+pub use national_id::{IterWithPrefix, MaskedNationalId, NationalId, NationalIdError, Parser};
+#[cfg(feature = "std")]
+pub use national_id_set::NationalIdSet;
+#[cfg(feature = "alloc")]
+pub use ocr::OcrCorrection;
+#[cfg(feature = "alloc")]
+pub use operator_table::{OperatorTable, OperatorTableError};
+#[cfg(feature = "alloc")]
+pub use passport_number::{PassportNumber, PassportNumberError};
+#[cfg(feature = "alloc")]
+pub use payment_card::{Cvv2Error, Expiry, ExpiryError, PaymentCard, PaymentCardError};
+#[cfg(feature = "alloc")]
+pub use person::{Person, PersonBuilder};
+#[cfg(feature = "alloc")]
+pub use person_id::{PersonId, PersonIdError};
+#[cfg(feature = "alloc")]
+pub use postal_code::{PostalCode, PostalCodeError};
+pub use prefix_table::Location;
+#[cfg(feature = "alloc")]
+pub use prefix_table::{PrefixTable, PrefixTableError};
+pub use province::Province;
+pub use pseudonym::{Pseudonym, Pseudonymizer};
+#[cfg(feature = "alloc")]
+pub use registration_number::{RegistrationNumber, RegistrationNumberError};
+#[cfg(feature = "alloc")]
+pub use registry::{Validator, ValidatorRegistry};
+#[cfg(feature = "alloc")]
+pub use sayad_id::{SayadId, SayadIdError};
+#[cfg(feature = "alloc")]
+pub use sheba::{Sheba, ShebaError};
+#[cfg(feature = "alloc")]
+pub use social_security::{InsuranceNumber, InsuranceNumberError, WorkshopCode, WorkshopCodeError};
+#[cfg(feature = "alloc")]
+pub use unverified::{RawNationalId, Unverified};
+#[cfg(feature = "alloc")]
+pub use validate::Validate;
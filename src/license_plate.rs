@@ -0,0 +1,525 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::locale::LocalizedError;
+use crate::normalize::{is_separator, normalize_digit, to_persian_digits};
+use crate::prefix_table::Location;
+use crate::province::Province;
+
+/// Letters used on Iranian plates. `ا` stands for `الف`; `D` and `S` are diplomatic.
+const LETTERS: [char; 26] = [
+    'ا', 'ب', 'پ', 'ت', 'ث', 'ج', 'د', 'ز', 'ژ', 'س', 'ش', 'ص', 'ط',
+    'ع', 'ف', 'ق', 'ک', 'گ', 'ل', 'م', 'ن', 'و', 'ه', 'ی', 'D', 'S',
+];
+
+/// Vehicle class a plate is issued for.
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum PlateKind {
+    Private,
+    Taxi,
+    PublicTransport,
+    Government,
+    Police,
+    Military,
+    Agricultural,
+    Disabled,
+    Diplomatic,
+    Temporary,
+    Motorcycle,
+    FreeZone(FreeZone),
+}
+
+/// Free trade zones issuing their own plates.
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum FreeZone {
+    Kish,
+    Qeshm,
+    Chabahar,
+    Aras,
+    Anzali,
+    Arvand,
+    Maku,
+}
+
+const FREE_ZONES: [(FreeZone, &str); 7] = [
+    (FreeZone::Kish, "کیش"),
+    (FreeZone::Qeshm, "قشم"),
+    (FreeZone::Chabahar, "چابهار"),
+    (FreeZone::Aras, "ارس"),
+    (FreeZone::Anzali, "انزلی"),
+    (FreeZone::Arvand, "اروند"),
+    (FreeZone::Maku, "ماکو"),
+];
+
+impl FreeZone {
+    pub fn persian_name(&self) -> &'static str {
+        FREE_ZONES.iter().find(|(zone, _)| zone == self).unwrap().1
+    }
+}
+
+/// Bundled snapshot of region code allocations: (code, province, city).
+///
+/// Codes used by several cities of a province resolve to its capital; codes not listed
+/// here resolve to `None`.
+const REGIONS: [(u8, Province, &str); 40] = [
+    (10, Province::Tehran, "Tehran"),
+    (11, Province::Tehran, "Tehran"),
+    (20, Province::Tehran, "Tehran"),
+    (22, Province::Tehran, "Tehran"),
+    (33, Province::Tehran, "Tehran"),
+    (44, Province::Tehran, "Tehran"),
+    (55, Province::Tehran, "Tehran"),
+    (66, Province::Tehran, "Tehran"),
+    (77, Province::Tehran, "Tehran"),
+    (88, Province::Tehran, "Tehran"),
+    (99, Province::Tehran, "Tehran"),
+    (12, Province::RazaviKhorasan, "Mashhad"),
+    (13, Province::Isfahan, "Isfahan"),
+    (14, Province::Khuzestan, "Ahvaz"),
+    (15, Province::EastAzerbaijan, "Tabriz"),
+    (16, Province::Qom, "Qom"),
+    (17, Province::WestAzerbaijan, "Urmia"),
+    (18, Province::Hamadan, "Hamadan"),
+    (19, Province::Kermanshah, "Kermanshah"),
+    (31, Province::Lorestan, "Khorramabad"),
+    (32, Province::NorthKhorasan, "Bojnurd"),
+    (45, Province::Kerman, "Kerman"),
+    (46, Province::Gilan, "Rasht"),
+    (47, Province::Markazi, "Arak"),
+    (48, Province::Bushehr, "Bushehr"),
+    (49, Province::KohgiluyehAndBoyerAhmad, "Yasuj"),
+    (51, Province::Kurdistan, "Sanandaj"),
+    (52, Province::SouthKhorasan, "Birjand"),
+    (54, Province::Yazd, "Yazd"),
+    (59, Province::Golestan, "Gorgan"),
+    (62, Province::Mazandaran, "Sari"),
+    (63, Province::Fars, "Shiraz"),
+    (68, Province::Alborz, "Karaj"),
+    (71, Province::ChaharmahalAndBakhtiari, "Shahrekord"),
+    (79, Province::Qazvin, "Qazvin"),
+    (84, Province::Hormozgan, "Bandar Abbas"),
+    (85, Province::SistanAndBaluchestan, "Zahedan"),
+    (86, Province::Semnan, "Semnan"),
+    (87, Province::Zanjan, "Zanjan"),
+    (91, Province::Ardabil, "Ardabil"),
+];
+
+/// The place a plate region code is allocated to, from the bundled table.
+pub fn region_location(code: u8) -> Option<Location<'static>> {
+    REGIONS.iter()
+        .find(|(c, _, _)| *c == code)
+        .map(|(_, province, city)| Location { province: *province, city })
+}
+
+/// Region codes allocated to `province` in the bundled table, in ascending order.
+pub fn region_codes_of(province: Province) -> impl Iterator<Item = u8> {
+    let mut codes: Vec<u8> = REGIONS.iter().filter(|(_, p, _)| *p == province).map(|(code, _, _)| *code).collect();
+    codes.sort_unstable();
+    codes.into_iter()
+}
+
+fn normalize(value: &str) -> Vec<char> {
+    value.chars()
+        .filter(|c| !is_separator(*c) && *c != '|')
+        .map(|c| match normalize_digit(c) {
+            'ي' | 'ى' => 'ی',
+            'ك' => 'ک',
+            c => c,
+        })
+        .collect()
+}
+
+fn number(chars: &[char]) -> Result<u32, LicensePlateError> {
+    chars.iter()
+        .try_fold(0, |n, c| c.to_digit(10).map(|d| n * 10 + d))
+        .ok_or(LicensePlateError::InvalidFormat)
+}
+
+/// Iranian vehicle license plate, e.g. `12ب345-67`: two digits, a Persian letter,
+/// three digits and a two-digit region code.
+#[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct LicensePlate {
+    prefix_number: u8,
+    letter: char,
+    serial_number: u16,
+    region_code: u8,
+}
+
+impl TryFrom<&str> for LicensePlate {
+    type Error = LicensePlateError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let chars = normalize(&value.replace("الف", "ا").replace("ایران", ""));
+        if chars.len() != 8 {
+            return Err(LicensePlateError::InvalidFormat);
+        }
+
+        let prefix_number = number(&chars[0..2])? as u8;
+        let letter = chars[2].to_ascii_uppercase();
+        let serial_number = number(&chars[3..6])? as u16;
+        let region_code = number(&chars[6..8])? as u8;
+
+        if !LETTERS.contains(&letter) {
+            return Err(LicensePlateError::UnknownLetter(letter));
+        }
+        Ok(LicensePlate { prefix_number, letter, serial_number, region_code })
+    }
+}
+
+impl TryFrom<String> for LicensePlate {
+    type Error = LicensePlateError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        LicensePlate::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for LicensePlate {
+    type Error = LicensePlateError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        LicensePlate::try_from(value.as_str())
+    }
+}
+
+impl FromStr for LicensePlate {
+    type Err = LicensePlateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LicensePlate::try_from(s)
+    }
+}
+
+impl LicensePlate {
+    /// The two digits left of the letter.
+    pub fn prefix_number(&self) -> u8 {
+        self.prefix_number
+    }
+
+    /// The plate letter; `ا` stands for `الف`.
+    pub fn letter(&self) -> char {
+        self.letter
+    }
+
+    /// The three digits right of the letter.
+    pub fn serial_number(&self) -> u16 {
+        self.serial_number
+    }
+
+    /// The two-digit region code printed under "ایران".
+    pub fn region_code(&self) -> u8 {
+        self.region_code
+    }
+
+    /// The place the region code is allocated to, if the bundled table knows it.
+    pub fn region(&self) -> Option<Location<'static>> {
+        region_location(self.region_code)
+    }
+
+    /// The vehicle class, determined by the plate letter.
+    pub fn kind(&self) -> PlateKind {
+        match self.letter {
+            'ت' => PlateKind::Taxi,
+            'ع' => PlateKind::PublicTransport,
+            'ا' => PlateKind::Government,
+            'پ' => PlateKind::Police,
+            'ث' | 'ز' | 'ش' => PlateKind::Military,
+            'ک' => PlateKind::Agricultural,
+            'ژ' => PlateKind::Disabled,
+            'D' | 'S' => PlateKind::Diplomatic,
+            'گ' => PlateKind::Temporary,
+            _ => PlateKind::Private,
+        }
+    }
+
+    /// The displayed form with Persian digits, e.g. `۱۲ب۳۴۵-۶۷`.
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(&self.to_string())
+    }
+}
+
+impl Display for LicensePlate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let letter = if self.letter == 'ا' { "الف".to_string() } else { self.letter.to_string() };
+        write!(f, "{:02}{}{:03}-{:02}", self.prefix_number, letter, self.serial_number, self.region_code)
+    }
+}
+
+/// Motorcycle plate: a three-digit city code above a five-digit number, e.g. `123-45678`.
+#[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct MotorcyclePlate {
+    city_code: u16,
+    number: u32,
+}
+
+impl TryFrom<&str> for MotorcyclePlate {
+    type Error = LicensePlateError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let chars = normalize(value);
+        if chars.len() != 8 {
+            return Err(LicensePlateError::InvalidFormat);
+        }
+
+        Ok(MotorcyclePlate { city_code: number(&chars[..3])? as u16, number: number(&chars[3..])? })
+    }
+}
+
+impl FromStr for MotorcyclePlate {
+    type Err = LicensePlateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MotorcyclePlate::try_from(s)
+    }
+}
+
+impl MotorcyclePlate {
+    pub fn city_code(&self) -> u16 {
+        self.city_code
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+}
+
+impl Display for MotorcyclePlate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:03}-{:05}", self.city_code, self.number)
+    }
+}
+
+/// Free-zone plate: a five-digit number with the zone name, e.g. `12345 کیش`.
+#[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct FreeZonePlate {
+    zone: FreeZone,
+    number: u32,
+}
+
+impl TryFrom<&str> for FreeZonePlate {
+    type Error = LicensePlateError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let chars = normalize(value);
+        let text: String = chars.iter().collect();
+        let (zone, name) = FREE_ZONES.iter()
+            .find(|(_, name)| text.starts_with(name) || text.ends_with(name))
+            .ok_or(LicensePlateError::InvalidFormat)?;
+
+        let digits: Vec<char> = text.replacen(name, "", 1).chars().collect();
+        if digits.len() != 5 {
+            return Err(LicensePlateError::InvalidFormat);
+        }
+        Ok(FreeZonePlate { zone: *zone, number: number(&digits)? })
+    }
+}
+
+impl FromStr for FreeZonePlate {
+    type Err = LicensePlateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FreeZonePlate::try_from(s)
+    }
+}
+
+impl FreeZonePlate {
+    pub fn zone(&self) -> FreeZone {
+        self.zone
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+}
+
+impl Display for FreeZonePlate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:05} {}", self.number, self.zone.persian_name())
+    }
+}
+
+/// Any Iranian plate layout.
+#[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub enum Plate {
+    Car(LicensePlate),
+    Motorcycle(MotorcyclePlate),
+    FreeZone(FreeZonePlate),
+}
+
+impl TryFrom<&str> for Plate {
+    type Error = LicensePlateError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(plate) = FreeZonePlate::try_from(value) {
+            return Ok(Plate::FreeZone(plate));
+        }
+        if let Ok(plate) = MotorcyclePlate::try_from(value) {
+            return Ok(Plate::Motorcycle(plate));
+        }
+        LicensePlate::try_from(value).map(Plate::Car)
+    }
+}
+
+impl FromStr for Plate {
+    type Err = LicensePlateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Plate::try_from(s)
+    }
+}
+
+impl Plate {
+    pub fn kind(&self) -> PlateKind {
+        match self {
+            Plate::Car(plate) => plate.kind(),
+            Plate::Motorcycle(_) => PlateKind::Motorcycle,
+            Plate::FreeZone(plate) => PlateKind::FreeZone(plate.zone()),
+        }
+    }
+
+    /// The displayed form of the plate with Persian digits, as painted on it.
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(&self.to_string())
+    }
+}
+
+impl Display for Plate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Plate::Car(plate) => plate.fmt(f),
+            Plate::Motorcycle(plate) => plate.fmt(f),
+            Plate::FreeZone(plate) => plate.fmt(f),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum LicensePlateError {
+    InvalidFormat,
+    UnknownLetter(char),
+}
+
+impl Error for LicensePlateError {}
+
+impl Display for LicensePlateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LicensePlateError::InvalidFormat => {
+                write!(f, "invalid license plate: expected two digits, a letter, three digits and a region code")
+            }
+            LicensePlateError::UnknownLetter(letter) => {
+                write!(f, "invalid license plate: '{}' is not a plate letter", letter)
+            }
+        }
+    }
+}
+
+impl LocalizedError for LicensePlateError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "پلاک نامعتبر است: ")?;
+        match self {
+            LicensePlateError::InvalidFormat => write!(f, "باید شامل دو رقم، یک حرف، سه رقم و کد منطقه باشد"),
+            LicensePlateError::UnknownLetter(letter) => write!(f, "«{}» حرف پلاک نیست", letter),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::license_plate::{
+        region_codes_of, region_location, FreeZone, FreeZonePlate, LicensePlate, LicensePlateError, MotorcyclePlate, Plate,
+        PlateKind, REGIONS,
+    };
+    use crate::prefix_table::Location;
+    use crate::province::Province;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_parse_components() {
+        let plate = LicensePlate::try_from("12ب345-67").unwrap();
+        assert_eq!(plate.prefix_number(), 12);
+        assert_eq!(plate.letter(), 'ب');
+        assert_eq!(plate.serial_number(), 345);
+        assert_eq!(plate.region_code(), 67);
+    }
+
+    #[test]
+    fn test_accepted_formats() {
+        let expected = LicensePlate::try_from("12ب345-67");
+        assert_eq!(LicensePlate::try_from("۱۲ ب ۳۴۵ ایران ۶۷"), expected);
+        assert_eq!(LicensePlate::try_from("12 ب 345 | 67"), expected);
+        assert_eq!(LicensePlate::try_from("12ي345-67").unwrap().letter(), 'ی');
+        assert_eq!(LicensePlate::try_from("12الف345-67").unwrap().letter(), 'ا');
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(LicensePlate::try_from("۰۵ ج ۰۴۵ ایران ۱۰").unwrap().to_string(), "05ج045-10");
+        assert_eq!(LicensePlate::try_from("12الف345-67").unwrap().to_string(), "12الف345-67");
+        assert_eq!(LicensePlate::try_from("12ب345-67").unwrap().to_persian_digits(), "۱۲ب۳۴۵-۶۷");
+    }
+
+    #[test]
+    fn test_region() {
+        let region = LicensePlate::try_from("12ب345-15").unwrap().region();
+        assert_eq!(region, Some(Location { province: Province::EastAzerbaijan, city: "Tabriz" }));
+        assert_eq!(LicensePlate::try_from("12ب345-88").unwrap().region().unwrap().province, Province::Tehran);
+        assert_eq!(region_location(0), None);
+        assert_eq!(region_codes_of(Province::Fars).collect::<Vec<_>>(), vec![63]);
+        assert_eq!(region_codes_of(Province::Tehran).count(), 11);
+
+        let mut codes: Vec<u8> = REGIONS.iter().map(|(code, _, _)| *code).collect();
+        codes.sort_unstable();
+        codes.dedup();
+        assert_eq!(codes.len(), REGIONS.len());
+    }
+
+    #[test]
+    fn test_kind_from_letter() {
+        let kind = |p: &str| LicensePlate::try_from(p).unwrap().kind();
+        assert_eq!(kind("12ب345-67"), PlateKind::Private);
+        assert_eq!(kind("12ت345-67"), PlateKind::Taxi);
+        assert_eq!(kind("12ع345-67"), PlateKind::PublicTransport);
+        assert_eq!(kind("12پ345-67"), PlateKind::Police);
+        assert_eq!(kind("12الف345-67"), PlateKind::Government);
+        assert_eq!(kind("12گ345-67"), PlateKind::Temporary);
+        assert_eq!(kind("12d345-67"), PlateKind::Diplomatic);
+    }
+
+    #[test]
+    fn test_motorcycle_plate() {
+        let plate = MotorcyclePlate::try_from("۱۲۳ ۴۵۶۷۸").unwrap();
+        assert_eq!(plate.city_code(), 123);
+        assert_eq!(plate.number(), 45678);
+        assert_eq!(plate.to_string(), "123-45678");
+    }
+
+    #[test]
+    fn test_free_zone_plate() {
+        let plate = FreeZonePlate::try_from("12345 کیش").unwrap();
+        assert_eq!(plate.zone(), FreeZone::Kish);
+        assert_eq!(plate.number(), 12345);
+        assert_eq!(FreeZonePlate::try_from("قشم ۵۴۳۲۱").unwrap().zone(), FreeZone::Qeshm);
+        assert_eq!(FreeZonePlate::try_from("1234 کیش"), Err(LicensePlateError::InvalidFormat));
+    }
+
+    #[test]
+    fn test_plate_detects_layout() {
+        let kind = |p: &str| Plate::try_from(p).unwrap().kind();
+        assert_eq!(kind("12ب345-67"), PlateKind::Private);
+        assert_eq!(kind("123-45678"), PlateKind::Motorcycle);
+        assert_eq!(kind("12345 اروند"), PlateKind::FreeZone(FreeZone::Arvand));
+        assert!(Plate::try_from("12345").is_err());
+    }
+
+    #[test]
+    fn test_invalid_license_plate() {
+        assert_eq!(LicensePlate::try_from("12ب34-67"), Err(LicensePlateError::InvalidFormat));
+        assert_eq!(LicensePlate::try_from("1ب2345-67"), Err(LicensePlateError::InvalidFormat));
+        assert_eq!(LicensePlate::try_from("12x345-67"), Err(LicensePlateError::UnknownLetter('X')));
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::fmt::{Display, Formatter, Write};
+
+use crate::normalize::persian_digit;
+
+/// Language of error messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// English, as written by `Display`.
+    En,
+    /// Persian, with Persian digits.
+    Fa,
+}
+
+/// An error whose message is also available in Persian, for showing directly in Persian UIs.
+pub trait LocalizedError: Display {
+    /// Writes the Persian message. Digits may be written as ASCII digits; they are
+    /// converted to Persian digits by [`LocalizedError::localized`].
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result;
+
+    /// The message in `locale`, as a value implementing `Display`.
+    fn localized(&self, locale: Locale) -> Localized<'_, Self>
+    where
+        Self: Sized,
+    {
+        Localized { error: self, locale }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_localized_string(&self, locale: Locale) -> String
+    where
+        Self: Sized,
+    {
+        self.localized(locale).to_string()
+    }
+}
+
+/// Displays an error in a given locale; see [`LocalizedError::localized`].
+pub struct Localized<'a, E: ?Sized> {
+    error: &'a E,
+    locale: Locale,
+}
+
+impl<E: LocalizedError + ?Sized> Display for Localized<'_, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.locale {
+            Locale::En => self.error.fmt(f),
+            Locale::Fa => write!(PersianDigits(f), "{}", Persian(self.error)),
+        }
+    }
+}
+
+/// Displays the Persian message of an error, with ASCII digits.
+struct Persian<'a, E: ?Sized>(&'a E);
+
+impl<E: LocalizedError + ?Sized> Display for Persian<'_, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt_persian(f)
+    }
+}
+
+/// Replaces ASCII digits with Persian ones while writing.
+struct PersianDigits<'a, 'b>(&'a mut Formatter<'b>);
+
+impl Write for PersianDigits<'_, '_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        s.chars().try_for_each(|c| self.0.write_char(persian_digit(c)))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::economic_code::EconomicCode;
+    use crate::locale::{Locale, LocalizedError};
+    use crate::national_id::NationalIdError;
+    use crate::sheba::ShebaError;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_persian_messages() {
+        let err = NationalIdError::InvalidCheckDigit { expected: 7, found: 8 };
+        assert_eq!(err.to_localized_string(Locale::Fa), "کد ملی نامعتبر است: رقم کنترل باید ۷ باشد، نه ۸");
+        assert_eq!(err.to_localized_string(Locale::En), err.to_string());
+        assert_eq!(format!("{}", ShebaError::InvalidLength(3).localized(Locale::Fa)), "شماره شبا نامعتبر است: باید ۲۶ نویسه باشد، ۳ نویسه وارد شده");
+
+        let err = EconomicCode::try_from("0040010008").unwrap_err();
+        assert_eq!(err.to_localized_string(Locale::Fa), "کد اقتصادی نامعتبر است: کد ملی نامعتبر است: رقم کنترل باید ۷ باشد، نه ۸");
+    }
+}
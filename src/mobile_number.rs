@@ -0,0 +1,407 @@
+use alloc::format;
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::fake::Rng;
+use crate::locale::LocalizedError;
+use crate::operator_table::OperatorTable;
+use crate::normalize::{is_separator, normalize_digit, to_persian_digits};
+
+/// Iranian mobile network operators, including MVNOs.
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum Operator {
+    Mci,
+    Irancell,
+    Rightel,
+    Mtce,
+    Taliya,
+    TeleKish,
+    ShatelMobile,
+    Aptel,
+    LotusTel,
+    Samantel,
+}
+
+const OPERATORS: [Operator; 10] = [
+    Operator::Mci,
+    Operator::Irancell,
+    Operator::Rightel,
+    Operator::Mtce,
+    Operator::Taliya,
+    Operator::TeleKish,
+    Operator::ShatelMobile,
+    Operator::Aptel,
+    Operator::LotusTel,
+    Operator::Samantel,
+];
+
+impl Operator {
+    pub fn english_name(&self) -> &'static str {
+        match self {
+            Operator::Mci => "MCI (Hamrah-e Aval)",
+            Operator::Irancell => "Irancell",
+            Operator::Rightel => "RighTel",
+            Operator::Mtce => "MTCE (Espadan)",
+            Operator::Taliya => "Taliya",
+            Operator::TeleKish => "TeleKish",
+            Operator::ShatelMobile => "Shatel Mobile",
+            Operator::Aptel => "Aptel",
+            Operator::LotusTel => "Lotus Tel",
+            Operator::Samantel => "Samantel",
+        }
+    }
+
+    pub fn persian_name(&self) -> &'static str {
+        match self {
+            Operator::Mci => "همراه اول",
+            Operator::Irancell => "ایرانسل",
+            Operator::Rightel => "رایتل",
+            Operator::Mtce => "اسپادان",
+            Operator::Taliya => "تالیا",
+            Operator::TeleKish => "تله‌کیش",
+            Operator::ShatelMobile => "شاتل موبایل",
+            Operator::Aptel => "آپتل",
+            Operator::LotusTel => "لوتوس‌تل",
+            Operator::Samantel => "سامانتل",
+        }
+    }
+}
+
+/// Parses the English name, with or without its parenthesized part (e.g. `MCI`), ignoring
+/// case, or the Persian name.
+impl FromStr for Operator {
+    type Err = UnknownOperator;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        OPERATORS.iter()
+            .find(|operator| {
+                let name = operator.english_name();
+                let short = name.split(" (").next().unwrap();
+                name.eq_ignore_ascii_case(s) || short.eq_ignore_ascii_case(s) || operator.persian_name() == s
+            })
+            .copied()
+            .ok_or(UnknownOperator)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct UnknownOperator;
+
+impl Error for UnknownOperator {}
+
+impl Display for UnknownOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown iranian mobile operator")
+    }
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.english_name())
+    }
+}
+
+/// Number prefix allocations (longest prefix wins).
+pub(crate) const OPERATOR_PREFIXES: [(&str, Operator); 40] = [
+    ("0910", Operator::Mci),
+    ("0911", Operator::Mci),
+    ("0912", Operator::Mci),
+    ("0913", Operator::Mci),
+    ("0914", Operator::Mci),
+    ("0915", Operator::Mci),
+    ("0916", Operator::Mci),
+    ("0917", Operator::Mci),
+    ("0918", Operator::Mci),
+    ("0919", Operator::Mci),
+    ("0990", Operator::Mci),
+    ("0991", Operator::Mci),
+    ("0992", Operator::Mci),
+    ("0993", Operator::Mci),
+    ("0994", Operator::Mci),
+    ("0900", Operator::Irancell),
+    ("0901", Operator::Irancell),
+    ("0902", Operator::Irancell),
+    ("0903", Operator::Irancell),
+    ("0904", Operator::Irancell),
+    ("0905", Operator::Irancell),
+    ("0930", Operator::Irancell),
+    ("0933", Operator::Irancell),
+    ("0935", Operator::Irancell),
+    ("0936", Operator::Irancell),
+    ("0937", Operator::Irancell),
+    ("0938", Operator::Irancell),
+    ("0939", Operator::Irancell),
+    ("0941", Operator::Irancell),
+    ("0920", Operator::Rightel),
+    ("0921", Operator::Rightel),
+    ("0922", Operator::Rightel),
+    ("0923", Operator::Rightel),
+    ("0931", Operator::Mtce),
+    ("0932", Operator::Taliya),
+    ("0934", Operator::TeleKish),
+    ("09981", Operator::ShatelMobile),
+    ("09991", Operator::Aptel),
+    ("09990", Operator::LotusTel),
+    ("09999", Operator::Samantel),
+];
+
+/// Iranian mobile number, stored in the local `09xxxxxxxxx` form.
+///
+/// Accepts `09xxxxxxxxx`, `9xxxxxxxxx`, `+989xxxxxxxxx`, `00989xxxxxxxxx` and `989xxxxxxxxx`,
+/// with separators and Persian digits.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct MobileNumber(String);
+
+impl TryFrom<&str> for MobileNumber {
+    type Error = MobileNumberError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value: String = value.chars()
+            .filter(|c| !is_separator(*c) && !matches!(c, '(' | ')'))
+            .map(normalize_digit)
+            .collect();
+
+        let value = match value.strip_prefix("+98").or_else(|| value.strip_prefix("0098")) {
+            Some(rest) => format!("0{}", rest),
+            None if value.len() == 12 && value.starts_with("98") => format!("0{}", &value[2..]),
+            None if value.len() == 10 && value.starts_with('9') => format!("0{}", value),
+            None => value,
+        };
+
+        if !value.chars().all(|c| c.is_ascii_digit()) {
+            return Err(MobileNumberError::NonDigitCharacter);
+        }
+
+        if value.len() != 11 {
+            return Err(MobileNumberError::InvalidLength(value.len()));
+        }
+
+        if !value.starts_with("09") {
+            return Err(MobileNumberError::InvalidPrefix);
+        }
+        Ok(MobileNumber(value))
+    }
+}
+
+impl TryFrom<String> for MobileNumber {
+    type Error = MobileNumberError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        MobileNumber::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for MobileNumber {
+    type Error = MobileNumberError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        MobileNumber::try_from(value.as_str())
+    }
+}
+
+impl FromStr for MobileNumber {
+    type Err = MobileNumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MobileNumber::try_from(s)
+    }
+}
+
+impl MobileNumber {
+    /// Generates a random valid number with a prefix from the bundled operator table.
+    pub fn random(rng: &mut Rng) -> Self {
+        let (prefix, _) = rng.choose(&OPERATOR_PREFIXES);
+        MobileNumber(rng.digits_after(prefix, 11))
+    }
+
+    /// Generates a random valid number with one of the prefixes allocated to `operator`.
+    pub fn random_for_operator(operator: Operator, rng: &mut Rng) -> Self {
+        let count = OPERATOR_PREFIXES.iter().filter(|(_, o)| *o == operator).count();
+        let (prefix, _) = OPERATOR_PREFIXES.iter()
+            .filter(|(_, o)| *o == operator)
+            .nth(rng.below(count as u64) as usize)
+            .unwrap();
+        MobileNumber(rng.digits_after(prefix, 11))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The local `09…` form written with Persian digits, e.g. `۰۹۱۲۱۲۳۴۵۶۷`.
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    /// Local (trunk-prefixed) form, e.g. `09123456789`.
+    pub fn to_local(&self) -> String {
+        self.0.clone()
+    }
+
+    /// Canonical E.164 form, e.g. `+989123456789`.
+    pub fn to_e164(&self) -> String {
+        format!("+98{}", &self.0[1..])
+    }
+
+    /// Digits-only international form without the `+`, e.g. `989123456789`, as SMS
+    /// gateways usually expect.
+    pub fn to_compact(&self) -> String {
+        format!("98{}", &self.0[1..])
+    }
+
+    /// Local form grouped for display, e.g. `0912 345 6789`; the same as
+    /// `format!("{:#}", number)`.
+    pub fn formatted(&self) -> String {
+        format!("{:#}", self)
+    }
+
+    /// International form grouped for display, e.g. `+98 912 345 6789`.
+    pub fn formatted_international(&self) -> String {
+        format!("+98 {} {} {}", &self.0[1..4], &self.0[4..7], &self.0[7..])
+    }
+
+    /// [`MobileNumber::formatted`] with Persian digits, e.g. `۰۹۱۲ ۳۴۵ ۶۷۸۹`.
+    pub fn formatted_persian(&self) -> String {
+        to_persian_digits(&self.formatted())
+    }
+
+    /// Like [`MobileNumber::operator`], using a table loaded at runtime instead of the
+    /// bundled one.
+    pub fn operator_in(&self, table: &OperatorTable) -> Option<Operator> {
+        table.operator(self)
+    }
+
+    /// The operator the number prefix is allocated to, if known.
+    pub fn operator(&self) -> Option<Operator> {
+        OPERATOR_PREFIXES.iter()
+            .filter(|(prefix, _)| self.0.starts_with(prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, operator)| *operator)
+    }
+}
+
+impl AsRef<str> for MobileNumber {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// The local form; the alternate form (`{:#}`) groups it as `0912 345 6789`.
+impl Display for MobileNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            write!(f, "{} {} {}", &self.0[..4], &self.0[4..7], &self.0[7..])
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum MobileNumberError {
+    InvalidLength(usize),
+    NonDigitCharacter,
+    InvalidPrefix,
+}
+
+impl Error for MobileNumberError {}
+
+impl Display for MobileNumberError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MobileNumberError::InvalidLength(len) => {
+                write!(f, "invalid mobile number: expected 11 digits in local form, got {}", len)
+            }
+            MobileNumberError::NonDigitCharacter => write!(f, "invalid mobile number: contains non-digit characters"),
+            MobileNumberError::InvalidPrefix => write!(f, "invalid mobile number: must start with 09"),
+        }
+    }
+}
+
+impl LocalizedError for MobileNumberError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "شماره همراه نامعتبر است: ")?;
+        match self {
+            MobileNumberError::InvalidLength(len) => write!(f, "باید 11 رقم باشد، {} رقم وارد شده", len),
+            MobileNumberError::NonDigitCharacter => write!(f, "فقط باید شامل رقم باشد"),
+            MobileNumberError::InvalidPrefix => write!(f, "باید با 09 شروع شود"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::fake::Rng;
+    use crate::mobile_number::{MobileNumber, MobileNumberError, Operator};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_random_numbers_are_valid() {
+        let mut rng = Rng::new(1);
+        for _ in 0..100 {
+            let number = MobileNumber::random(&mut rng);
+            assert_eq!(MobileNumber::try_from(number.as_str()), Ok(number.clone()));
+            assert!(number.operator().is_some());
+
+            let number = MobileNumber::random_for_operator(Operator::Rightel, &mut rng);
+            assert_eq!(MobileNumber::try_from(number.as_str()).unwrap().operator(), Some(Operator::Rightel));
+        }
+    }
+
+    #[test]
+    fn test_accepted_formats() {
+        for input in &["09123456789", "9123456789", "+989123456789", "00989123456789", "989123456789",
+                       "0912 345 6789", "+98 (912) 345-6789", "۰۹۱۲۳۴۵۶۷۸۹"] {
+            assert_eq!(MobileNumber::try_from(*input).unwrap().as_str(), "09123456789", "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_local_and_e164_forms() {
+        let number = MobileNumber::try_from("+98 912 345 6789").unwrap();
+        assert_eq!(number.to_local(), "09123456789");
+        assert_eq!(number.to_e164(), "+989123456789");
+        assert_eq!(MobileNumber::try_from(number.to_e164()), MobileNumber::try_from(number.to_local()));
+        assert_eq!(number, MobileNumber::try_from("09123456789").unwrap());
+    }
+
+    #[test]
+    fn test_display_forms() {
+        let number = MobileNumber::try_from("۰۹۱۲۳۴۵۶۷۸۹").unwrap();
+        assert_eq!(number.to_string(), "09123456789");
+        assert_eq!(number.formatted(), "0912 345 6789");
+        assert_eq!(format!("{:#}", number), number.formatted());
+        assert_eq!(number.formatted_international(), "+98 912 345 6789");
+        assert_eq!(number.to_compact(), "989123456789");
+        assert_eq!(number.formatted_persian(), "۰۹۱۲ ۳۴۵ ۶۷۸۹");
+        for form in [number.formatted(), number.formatted_international(), number.to_compact(), number.formatted_persian()] {
+            assert_eq!(MobileNumber::try_from(form), Ok(number.clone()));
+        }
+    }
+
+    #[test]
+    fn test_invalid_mobile_number() {
+        assert_eq!(MobileNumber::try_from("0912345678"), Err(MobileNumberError::InvalidLength(10)));
+        assert_eq!(MobileNumber::try_from("0912345678a"), Err(MobileNumberError::NonDigitCharacter));
+        assert_eq!(MobileNumber::try_from("02123456789"), Err(MobileNumberError::InvalidPrefix));
+    }
+
+    #[test]
+    fn test_operator() {
+        let operator = |n: &str| MobileNumber::try_from(n).unwrap().operator();
+        assert_eq!(operator("09123456789"), Some(Operator::Mci));
+        assert_eq!(operator("09351234567"), Some(Operator::Irancell));
+        assert_eq!(operator("09211234567"), Some(Operator::Rightel));
+        assert_eq!(operator("09981234567"), Some(Operator::ShatelMobile));
+        assert_eq!(operator("09971234567"), None);
+        assert_eq!(Operator::Irancell.persian_name(), "ایرانسل");
+    }
+}
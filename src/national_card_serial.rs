@@ -0,0 +1,155 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::locale::LocalizedError;
+use crate::normalize::{is_separator, normalize_digit, to_persian_digits};
+
+/// The serial printed on the back of the smart national card (سریال کارت ملی): two
+/// digits, a Latin letter and seven digits, e.g. `12K3456789`.
+///
+/// The letter is upper-cased and Persian digits are accepted. The serial identifies the
+/// card rather than the person, so a reissued card has a new one; only the shape is
+/// validated.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct NationalCardSerial(String);
+
+impl TryFrom<&str> for NationalCardSerial {
+    type Error = NationalCardSerialError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let chars: Vec<char> = value.chars()
+            .filter(|c| !is_separator(*c))
+            .map(|c| normalize_digit(c).to_ascii_uppercase())
+            .collect();
+        if chars.len() != 10 {
+            return Err(NationalCardSerialError::InvalidLength(chars.len()));
+        }
+        if !chars[2].is_ascii_uppercase() {
+            return Err(NationalCardSerialError::InvalidLetter(chars[2]));
+        }
+        if !chars[..2].iter().chain(&chars[3..]).all(|c| c.is_ascii_digit()) {
+            return Err(NationalCardSerialError::NonDigitCharacter);
+        }
+        Ok(NationalCardSerial(chars.into_iter().collect()))
+    }
+}
+
+impl TryFrom<String> for NationalCardSerial {
+    type Error = NationalCardSerialError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        NationalCardSerial::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for NationalCardSerial {
+    type Error = NationalCardSerialError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        NationalCardSerial::try_from(value.as_str())
+    }
+}
+
+impl FromStr for NationalCardSerial {
+    type Err = NationalCardSerialError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NationalCardSerial::try_from(s)
+    }
+}
+
+impl NationalCardSerial {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The serial with its digits in Persian; the letter is kept.
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    /// The letter after the first two digits.
+    pub fn letter(&self) -> char {
+        self.0.as_bytes()[2] as char
+    }
+}
+
+impl AsRef<str> for NationalCardSerial {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for NationalCardSerial {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum NationalCardSerialError {
+    InvalidLength(usize),
+    InvalidLetter(char),
+    NonDigitCharacter,
+}
+
+impl Error for NationalCardSerialError {}
+
+impl Display for NationalCardSerialError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NationalCardSerialError::InvalidLength(len) => {
+                write!(f, "invalid national card serial: expected 10 characters, got {}", len)
+            }
+            NationalCardSerialError::InvalidLetter(c) => {
+                write!(f, "invalid national card serial: third character should be a latin letter, found `{}`", c)
+            }
+            NationalCardSerialError::NonDigitCharacter => {
+                write!(f, "invalid national card serial: only the third character may be a letter")
+            }
+        }
+    }
+}
+
+impl LocalizedError for NationalCardSerialError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "سریال کارت ملی نامعتبر است: ")?;
+        match self {
+            NationalCardSerialError::InvalidLength(len) => write!(f, "باید ۱۰ نویسه باشد، {} نویسه وارد شده", len),
+            NationalCardSerialError::InvalidLetter(c) => write!(f, "نویسه سوم باید حرف لاتین باشد، «{}» وارد شده", c),
+            NationalCardSerialError::NonDigitCharacter => write!(f, "به جز نویسه سوم، همه باید رقم باشند"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::national_card_serial::{NationalCardSerial, NationalCardSerialError};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_validate_national_card_serial() {
+        let serial = NationalCardSerial::try_from(" ۱۲k ۳۴۵۶۷۸۹ ").unwrap();
+        assert_eq!(serial.as_str(), "12K3456789");
+        assert_eq!(serial.letter(), 'K');
+        assert_eq!(serial.to_persian_digits(), "۱۲K۳۴۵۶۷۸۹");
+        assert_eq!("00A0000000".parse::<NationalCardSerial>().unwrap().to_string(), "00A0000000");
+    }
+
+    #[test]
+    fn test_invalid_national_card_serial() {
+        assert_eq!(NationalCardSerial::try_from("12K345678"), Err(NationalCardSerialError::InvalidLength(9)));
+        assert_eq!(NationalCardSerial::try_from("1233456789"), Err(NationalCardSerialError::InvalidLetter('3')));
+        assert_eq!(NationalCardSerial::try_from("12ک3456789"), Err(NationalCardSerialError::InvalidLetter('ک')));
+        assert_eq!(NationalCardSerial::try_from("1AK3456789"), Err(NationalCardSerialError::NonDigitCharacter));
+        assert_eq!(NationalCardSerial::try_from("12K34567B9"), Err(NationalCardSerialError::NonDigitCharacter));
+    }
+}
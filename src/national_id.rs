@@ -0,0 +1,1256 @@
+use core::convert::TryFrom;
+use core::error::Error;
+use core::borrow::Borrow;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::ops::Deref;
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::batch::{self, BatchReport};
+#[cfg(feature = "alloc")]
+use crate::diagnostics::{self, ChecksumExplanation, ValidationReport};
+use crate::fake::Rng;
+use crate::locale::LocalizedError;
+use crate::normalize::{is_separator, normalize_digit};
+#[cfg(feature = "alloc")]
+use crate::ocr::{self, OcrCorrection};
+#[cfg(feature = "alloc")]
+use crate::normalize::to_persian_digits;
+use crate::prefix_table::{self, Location};
+#[cfg(feature = "alloc")]
+use crate::prefix_table::PrefixTable;
+use crate::province::Province;
+use crate::pseudonym::{Pseudonym, Pseudonymizer};
+
+/// Validated national id, stored as its ten ASCII digits.
+#[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq)]
+pub struct NationalId([u8; 10]);
+
+impl TryFrom<&str> for NationalId {
+    type Error = NationalIdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Parser::new().allow_persian_digits().parse(value)
+    }
+}
+
+/// Configurable national id parser.
+///
+/// `Parser::new()` trims and zero-pads the input and accepts ASCII digits only;
+/// each builder method relaxes or tightens that policy.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Parser<'a> {
+    strict_length: bool,
+    persian_digits: bool,
+    strip_separators: bool,
+    reject_repeated_digits: bool,
+    denied: &'a [NationalId],
+}
+
+impl<'a> Parser<'a> {
+    pub fn new() -> Self {
+        Parser::default()
+    }
+
+    /// Requires exactly ten characters: no trimming and no zero-padding.
+    pub fn strict_length(mut self) -> Self {
+        self.strict_length = true;
+        self
+    }
+
+    /// Accepts Persian (۰–۹) and Arabic-Indic (٠–٩) digits.
+    pub fn allow_persian_digits(mut self) -> Self {
+        self.persian_digits = true;
+        self
+    }
+
+    /// Removes dashes, whitespace and zero-width characters before validation.
+    pub fn strip_separators(mut self) -> Self {
+        self.strip_separators = true;
+        self
+    }
+
+    /// Rejects placeholder codes such as `1111111111` where all ten digits are identical.
+    pub fn reject_repeated_digits(mut self) -> Self {
+        self.reject_repeated_digits = true;
+        self
+    }
+
+    /// Rejects the given ids although they are valid, e.g. published placeholders or ids
+    /// reserved for testing. The list is searched linearly.
+    pub fn deny(mut self, ids: &'a [NationalId]) -> Self {
+        self.denied = ids;
+        self
+    }
+
+    pub fn parse(&self, value: &str) -> Result<NationalId, NationalIdError> {
+        let value = if self.strict_length { value } else { value.trim() };
+
+        let mut digits = [b'0'; 10];
+        let mut len = 0;
+        let mut non_digit = false;
+        for c in value.chars() {
+            if self.strip_separators && is_separator(c) {
+                continue;
+            }
+            let c = if self.persian_digits { normalize_digit(c) } else { c };
+            if len < 10 {
+                match c.to_digit(10) {
+                    Some(d) => digits[len] = b'0' + d as u8,
+                    None => non_digit = true,
+                }
+            }
+            len += 1;
+        }
+
+        if len > 10 || (self.strict_length && len != 10) {
+            return Err(NationalIdError::InvalidLength(len));
+        }
+        if non_digit {
+            return Err(NationalIdError::NonDigitCharacter);
+        }
+        digits.copy_within(..len, 10 - len);
+        digits[..10 - len].fill(b'0');
+
+        let id = NationalId::from_ascii_digits(&digits)?;
+        if self.reject_repeated_digits && id.0.iter().all(|b| *b == id.0[0]) {
+            return Err(NationalIdError::RepeatedDigits);
+        }
+        if self.denied.contains(&id) {
+            return Err(NationalIdError::Denied);
+        }
+        Ok(id)
+    }
+}
+
+/// Checks `value` with the same rules as `NationalId::try_from`, without allocating.
+pub fn is_valid(value: &str) -> bool {
+    let value = value.trim();
+    let len = value.chars().count();
+    if len > 10 {
+        return false;
+    }
+
+    let mut digits = [0u32; 10];
+    for (slot, c) in digits[10 - len..].iter_mut().zip(value.chars()) {
+        match normalize_digit(c).to_digit(10) {
+            Some(d) => *slot = d,
+            None => return false,
+        }
+    }
+
+    let sum = weighted_sum(&digits);
+    sum != 0 && check_digit(sum) == digits[9]
+}
+
+/// Checks only the length and characters of `value`, not the check digit: `true` for
+/// one to ten digits after trimming, as `NationalId::try_from` accepts them.
+///
+/// Meant as a cheap prefilter that discards garbage before full validation; a plausible
+/// value may still be rejected by [`is_valid`].
+pub fn is_plausible(value: &str) -> bool {
+    let value = value.trim();
+    let mut len = 0;
+    for c in value.chars() {
+        if len == 10 || !normalize_digit(c).is_ascii_digit() {
+            return false;
+        }
+        len += 1;
+    }
+    len > 0
+}
+
+/// Computes the control digit for the first nine digits of a national id.
+///
+/// Shorter input is zero-padded on the left, as with parsing; Persian digits are accepted.
+pub fn compute_check_digit(first_nine: &str) -> Result<u8, NationalIdError> {
+    let digits = nine_digits(first_nine)?;
+    let sum = weighted_sum(&digits);
+    if sum == 0 {
+        return Err(NationalIdError::AllZeros);
+    }
+    Ok(check_digit(sum) as u8)
+}
+
+/// Trims, normalizes and zero-pads up to nine digits.
+fn nine_digits(value: &str) -> Result<[u32; 9], NationalIdError> {
+    let value = value.trim();
+    let len = value.chars().count();
+    if len > 9 {
+        return Err(NationalIdError::InvalidLength(len));
+    }
+
+    let mut digits = [0u32; 9];
+    for (slot, c) in digits[9 - len..].iter_mut().zip(value.chars()) {
+        *slot = normalize_digit(c).to_digit(10).ok_or(NationalIdError::NonDigitCharacter)?;
+    }
+    Ok(digits)
+}
+
+pub(crate) const fn weighted_sum(digits: &[u32]) -> u32 {
+    let mut sum = 0;
+    let mut i = 0;
+    while i < 9 {
+        sum += digits[i] * (10 - i) as u32;
+        i += 1;
+    }
+    sum
+}
+
+pub(crate) const fn check_digit(sum: u32) -> u32 {
+    let rem = sum % 11;
+    if rem < 2 { rem } else { 11 - rem }
+}
+
+fn random_with_prefix(prefix: &[u32], rng: &mut Rng) -> NationalId {
+    loop {
+        let mut digits = [0u32; 9];
+        digits[..prefix.len()].copy_from_slice(prefix);
+        for d in &mut digits[prefix.len()..] {
+            *d = rng.below(10) as u32;
+        }
+        let sum = weighted_sum(&digits);
+        if sum == 0 {
+            continue;
+        }
+
+        return NationalId::from_body(digits.iter().fold(0, |n, d| n * 10 + d));
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<String> for NationalId {
+    type Error = NationalIdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        NationalId::try_from(value.as_str())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&String> for NationalId {
+    type Error = NationalIdError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        NationalId::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<u64> for NationalId {
+    type Error = NationalIdError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        if value > 9_999_999_999 {
+            let len = (value.checked_ilog10().unwrap_or(0) + 1) as usize;
+            return Err(NationalIdError::InvalidLength(len));
+        }
+
+        let mut digits = [0u8; 10];
+        let mut value = value;
+        for slot in digits.iter_mut().rev() {
+            *slot = b'0' + (value % 10) as u8;
+            value /= 10;
+        }
+        NationalId::from_ascii_digits(&digits)
+    }
+}
+
+/// Same as [`NationalId::from_bytes`].
+impl TryFrom<&[u8]> for NationalId {
+    type Error = NationalIdError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        NationalId::from_bytes(value)
+    }
+}
+
+impl FromStr for NationalId {
+    type Err = NationalIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NationalId::try_from(s)
+    }
+}
+
+impl NationalId {
+    /// Validates ASCII digits straight from a byte buffer, such as a network packet or a
+    /// fixed-width record, without UTF-8 checking. Like `NationalId::try_from`, the input
+    /// is trimmed of ASCII whitespace and zero-padded; Persian digits are not accepted and
+    /// the length in the error is counted in bytes.
+    pub fn from_bytes(value: &[u8]) -> Result<Self, NationalIdError> {
+        let value = value.trim_ascii();
+        if value.len() > 10 {
+            return Err(NationalIdError::InvalidLength(value.len()));
+        }
+
+        let mut digits = [b'0'; 10];
+        digits[10 - value.len()..].copy_from_slice(value);
+        NationalId::from_ascii_digits(&digits)
+    }
+
+    /// Validates exactly ten ASCII digits, with no trimming, zero-padding or digit
+    /// normalization; the length in the error is counted in bytes.
+    ///
+    /// Being a `const fn`, this also backs the [`national_id!`](crate::national_id!) macro.
+    pub const fn from_ascii_digits(value: &[u8]) -> Result<Self, NationalIdError> {
+        let value = match value.first_chunk::<10>() {
+            Some(digits) if value.len() == 10 => *digits,
+            _ => return Err(NationalIdError::InvalidLength(value.len())),
+        };
+
+        let mut digits = [0u32; 10];
+        let mut i = 0;
+        while i < 10 {
+            if !value[i].is_ascii_digit() {
+                return Err(NationalIdError::NonDigitCharacter);
+            }
+            digits[i] = (value[i] - b'0') as u32;
+            i += 1;
+        }
+
+        let sum = weighted_sum(&digits);
+        if sum == 0 { return Err(NationalIdError::AllZeros); }
+
+        let expected = check_digit(sum);
+        if expected != digits[9] {
+            return Err(NationalIdError::InvalidCheckDigit { expected: expected as u8, found: digits[9] as u8 });
+        }
+        Ok(NationalId(value))
+    }
+
+    /// Parses `value` without trimming or zero-padding: it must be exactly ten digits.
+    pub fn parse_strict(value: &str) -> Result<Self, NationalIdError> {
+        Parser::new().strict_length().allow_persian_digits().parse(value)
+    }
+
+    /// Parses `value` after stripping dashes, whitespace and zero-width characters,
+    /// so inputs like `006-041825-7` are accepted.
+    pub fn parse_lenient(value: &str) -> Result<Self, NationalIdError> {
+        Parser::new().strip_separators().allow_persian_digits().parse(value)
+    }
+
+    /// Parses `value` leniently after replacing characters OCR commonly confuses with
+    /// digits (`O` → `0`, `l` → `1`, `S` → `5`, ...), returning the corrections made.
+    #[cfg(feature = "alloc")]
+    pub fn parse_ocr(value: &str) -> Result<(Self, Vec<OcrCorrection>), NationalIdError> {
+        ocr::parse(value)
+    }
+
+    /// Validates every input, collecting the valid ids and the index and error of each failure.
+    #[cfg(feature = "std")]
+    pub fn validate_all<'a, I: IntoIterator<Item = &'a str>>(inputs: I) -> BatchReport {
+        batch::report(inputs)
+    }
+
+    /// Checks `input` like `NationalId::try_from`, but reports every problem found instead
+    /// of stopping at the first one. Placeholder codes with ten identical digits are
+    /// reported too, although they pass the checksum.
+    #[cfg(feature = "alloc")]
+    pub fn diagnose(input: &str) -> ValidationReport {
+        diagnostics::diagnose(input)
+    }
+
+    /// Shows how the check digit of `input` is computed, without rejecting a mismatch,
+    /// so support staff can see why a code fails. Parsing follows `try_from`.
+    #[cfg(feature = "alloc")]
+    pub fn explain_checksum(input: &str) -> Result<ChecksumExplanation, NationalIdError> {
+        diagnostics::explain_checksum(input)
+    }
+
+    /// Likely intended ids for an input that fails only the checksum: first the input with
+    /// its check digit corrected, then any valid id obtained by swapping two adjacent digits.
+    /// Returns nothing for valid input or input that isn't ten digits or fewer.
+    #[cfg(feature = "alloc")]
+    pub fn suggestions(input: &str) -> Vec<NationalId> {
+        diagnostics::suggestions(input)
+    }
+
+    /// Appends the matching control digit to the first nine digits of a national id.
+    pub fn complete(first_nine: &str) -> Result<Self, NationalIdError> {
+        let digits = nine_digits(first_nine)?;
+        if weighted_sum(&digits) == 0 {
+            return Err(NationalIdError::AllZeros);
+        }
+        Ok(NationalId::from_body(digits.iter().fold(0, |n, d| n * 10 + d)))
+    }
+
+    /// Generates a random valid national id: nine random digits completed with the
+    /// matching check digit.
+    pub fn random(rng: &mut Rng) -> Self {
+        random_with_prefix(&[], rng)
+    }
+
+    /// Every valid national id starting with `prefix`, in ascending order and computed
+    /// lazily. Each nine-digit body has exactly one check digit, so a prefix of `n`
+    /// digits yields `10^(9 - n)` ids, less the all-zeros one for a prefix of zeros.
+    ///
+    /// The prefix has at most nine digits; Persian digits are accepted.
+    pub fn iter_with_prefix(prefix: &str) -> Result<IterWithPrefix, NationalIdError> {
+        let prefix = prefix.trim();
+        let len = prefix.chars().count();
+        if len > 9 {
+            return Err(NationalIdError::InvalidLength(len));
+        }
+
+        let mut start = 0;
+        for c in prefix.chars() {
+            start = start * 10 + normalize_digit(c).to_digit(10).ok_or(NationalIdError::NonDigitCharacter)?;
+        }
+        let span = 10u32.pow(9 - len as u32);
+        start *= span;
+        Ok(IterWithPrefix { next: start.max(1), end: start + span })
+    }
+
+    /// Generates a random valid national id whose prefix is allocated to `province`
+    /// in the bundled prefix table, drawn uniformly among that province's prefixes.
+    ///
+    /// The bundled table has prefixes for every province, so this only returns `None`
+    /// if that ever stops being true.
+    pub fn random_for_province(province: Province, rng: &mut Rng) -> Option<Self> {
+        let count = prefix_table::prefixes_of(province).count();
+        if count == 0 {
+            return None;
+        }
+
+        let prefix = prefix_table::prefixes_of(province).nth(rng.below(count as u64) as usize)?;
+        let mut digits = [0u32; 3];
+        for (d, c) in digits.iter_mut().zip(prefix.chars()) {
+            *d = c.to_digit(10)?;
+        }
+        Some(random_with_prefix(&digits, rng))
+    }
+
+    /// Generates a random valid national id from a province drawn in proportion to its
+    /// population, so that generated data is spread over provinces like real ids.
+    ///
+    /// Within the province a prefix is then drawn uniformly, as in
+    /// [`NationalId::random_for_province`]; busier offices such as Tehran's hold more
+    /// prefixes, so they are drawn more often.
+    pub fn random_by_population(rng: &mut Rng) -> Self {
+        let mut pick = rng.below(Province::all().map(|province| province.population() as u64).sum());
+        for province in Province::all() {
+            match pick.checked_sub(province.population() as u64) {
+                Some(rest) => pick = rest,
+                None => return NationalId::random_for_province(province, rng).unwrap(),
+            }
+        }
+        unreachable!("the pick is below the total weight")
+    }
+
+    /// Wraps ten ASCII digits **without validating them**, for ids from a trusted source
+    /// such as a column that only ever held validated ids.
+    ///
+    /// The checksum is only checked in debug builds. Passing an invalid id is not undefined
+    /// behavior, but the result is not a valid national id and methods relying on that
+    /// (`issuance_location`, `to_u64`, ...) may return nonsense or panic.
+    ///
+    /// # Panics
+    ///
+    /// If `value` is not exactly ten bytes long, and in debug builds if it is not a valid id.
+    pub fn new_unchecked(value: &str) -> Self {
+        debug_assert!(
+            NationalId::from_ascii_digits(value.as_bytes()).is_ok(),
+            "NationalId::new_unchecked called with an invalid id"
+        );
+        let mut buf = [0u8; 10];
+        buf.copy_from_slice(value.as_bytes());
+        NationalId(buf)
+    }
+
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.0).unwrap()
+    }
+
+    /// The ten digits written with Persian digits, e.g. `۰۰۴۰۰۱۰۰۰۷`.
+    #[cfg(feature = "alloc")]
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn into_inner(self) -> String {
+        self.as_str().to_string()
+    }
+
+    /// The id grouped as printed on the card, e.g. `006-041825-7`; same as `format!("{:#}", id)`.
+    #[cfg(feature = "alloc")]
+    pub fn formatted(&self) -> String {
+        format!("{:#}", self)
+    }
+
+    /// The id with all but the first three and last two digits hidden, e.g. `004*****07`.
+    #[cfg(feature = "alloc")]
+    pub fn masked(&self) -> String {
+        self.to_masked().to_string()
+    }
+
+    /// Wraps the id so that its `Display` and `Debug` output is always masked.
+    pub fn to_masked(self) -> MaskedNationalId {
+        MaskedNationalId(self)
+    }
+
+    /// Compares two ids in constant time, so checking user input against a stored id
+    /// doesn't reveal through timing how many leading digits matched.
+    pub fn ct_eq(&self, other: &NationalId) -> bool {
+        let diff = self.0.iter().zip(&other.0).fold(0, |acc, (a, b)| acc | (a ^ b));
+        core::hint::black_box(diff) == 0
+    }
+
+    /// Stable HMAC-SHA256 token for the id under `key`; see [`Pseudonymizer`] to reuse
+    /// one key across many ids.
+    pub fn pseudonymize(&self, key: &[u8]) -> Pseudonym {
+        Pseudonymizer::new(key).pseudonymize(self)
+    }
+
+    /// The ten digits as numbers, e.g. `[0, 0, 4, 0, 0, 1, 0, 0, 0, 7]`.
+    pub fn digits(&self) -> [u8; 10] {
+        self.0.map(|b| b - b'0')
+    }
+
+    /// The last digit, computed from the other nine.
+    pub fn check_digit(&self) -> u8 {
+        self.0[9] - b'0'
+    }
+
+    /// The first three digits, which identify the issuing office.
+    pub fn prefix(&self) -> &str {
+        &self.as_str()[..3]
+    }
+
+    /// Numeric form of the id; leading zeros are restored by [`NationalId::from_u64`].
+    pub fn to_u64(&self) -> u64 {
+        self.0.iter().fold(0, |n, b| n * 10 + (b - b'0') as u64)
+    }
+
+    /// A well-mixed hash of the id for shard keys and persisted hash indexes.
+    ///
+    /// Unlike [`Hash`], whose output depends on the hasher (and `std`'s is randomly
+    /// seeded per process), this is a fixed function of the id: the SplitMix64 finalizer
+    /// applied to [`NationalId::to_u64`]. It is the same on every platform and process,
+    /// and changing it is treated as a breaking change. Distinct ids never collide.
+    pub fn hash_u64(&self) -> u64 {
+        let mut x = self.to_u64().wrapping_add(0x9E37_79B9_7F4A_7C15);
+        x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        x ^ (x >> 31)
+    }
+
+    /// The first nine digits as a number; the check digit is implied by them.
+    #[cfg(feature = "std")]
+    pub(crate) fn body(&self) -> u32 {
+        (self.to_u64() / 10) as u32
+    }
+
+    /// Rebuilds an id from the value returned by [`NationalId::body`].
+    pub(crate) fn from_body(mut body: u32) -> Self {
+        let mut digits = [0u32; 10];
+        for slot in digits[..9].iter_mut().rev() {
+            *slot = body % 10;
+            body /= 10;
+        }
+        digits[9] = check_digit(weighted_sum(&digits));
+
+        let mut buf = [0u8; 10];
+        for (b, d) in buf.iter_mut().zip(&digits) {
+            *b = b'0' + *d as u8;
+        }
+        NationalId(buf)
+    }
+
+    /// Zero-pads `value` to ten digits and validates it.
+    pub fn from_u64(value: u64) -> Result<Self, NationalIdError> {
+        NationalId::try_from(value)
+    }
+
+    /// The province and city of the issuing office, looked up from the first three digits
+    /// in the bundled prefix table.
+    pub fn issuance_location(&self) -> Option<Location<'static>> {
+        prefix_table::lookup(self.prefix())
+    }
+
+    /// Like [`NationalId::issuance_location`], looking the prefix up in `table`.
+    #[cfg(feature = "alloc")]
+    pub fn issuance_location_in<'a>(&self, table: &'a PrefixTable) -> Option<Location<'a>> {
+        table.lookup(self.prefix())
+    }
+}
+
+impl AsRef<str> for NationalId {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Hashes like the canonical string, as required by the `Borrow<str>` impl.
+impl Hash for NationalId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+/// Lets maps and sets keyed by `NationalId` be queried with the canonical `&str` form.
+impl Borrow<str> for NationalId {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<NationalId> for String {
+    fn from(id: NationalId) -> Self {
+        id.into_inner()
+    }
+}
+
+/// Compares against the canonical ten-digit form, so `"40010007"` does not equal `0040010007`.
+impl PartialEq<str> for NationalId {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for NationalId {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<NationalId> for str {
+    fn eq(&self, other: &NationalId) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<NationalId> for &str {
+    fn eq(&self, other: &NationalId) -> bool {
+        *self == other.as_str()
+    }
+}
+
+/// Prints the ten digits; the alternate flag (`{:#}`) groups them as on the card, e.g. `006-041825-7`.
+impl Display for NationalId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let digits = self.as_str();
+        if f.alternate() {
+            write!(f, "{}-{}-{}", &digits[..3], &digits[3..9], &digits[9..])
+        } else {
+            write!(f, "{}", digits)
+        }
+    }
+}
+
+/// Prints the masked form, e.g. `NationalId("004*****07")`, so ids don't leak into logs,
+/// panics and error reports; use `Display` or [`NationalId::as_str`] for the full value.
+impl Debug for NationalId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NationalId(\"{}\")", self.to_masked())
+    }
+}
+
+/// A national id whose `Display` and `Debug` output is always masked, so it can be
+/// logged without leaking the full value.
+#[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct MaskedNationalId(NationalId);
+
+impl MaskedNationalId {
+    /// The wrapped id, with its full value.
+    pub fn unmasked(&self) -> NationalId {
+        self.0
+    }
+}
+
+impl From<NationalId> for MaskedNationalId {
+    fn from(id: NationalId) -> Self {
+        MaskedNationalId(id)
+    }
+}
+
+impl Display for MaskedNationalId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let digits = self.0.as_str();
+        write!(f, "{}*****{}", &digits[..3], &digits[8..])
+    }
+}
+
+impl Debug for MaskedNationalId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "MaskedNationalId(\"{}\")", self)
+    }
+}
+
+/// Iterator over the valid national ids with a given prefix, returned by
+/// [`NationalId::iter_with_prefix`].
+#[derive(Debug, Clone)]
+pub struct IterWithPrefix {
+    next: u32,
+    end: u32,
+}
+
+impl Iterator for IterWithPrefix {
+    type Item = NationalId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.end {
+            return None;
+        }
+        self.next += 1;
+        Some(NationalId::from_body(self.next - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.next = self.next.saturating_add(n as u32).min(self.end);
+        self.next()
+    }
+}
+
+impl DoubleEndedIterator for IterWithPrefix {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.next >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(NationalId::from_body(self.end))
+    }
+}
+
+impl ExactSizeIterator for IterWithPrefix {
+    fn len(&self) -> usize {
+        self.end.saturating_sub(self.next) as usize
+    }
+}
+
+impl FusedIterator for IterWithPrefix {}
+
+/// Deprecated: use [`NationalId::as_str`] or [`NationalId::into_inner`] instead.
+/// This impl will be removed in the next minor release.
+impl Deref for NationalId {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+/// Builds a [`NationalId`] from a string literal, checked at compile time.
+///
+/// The literal must be exactly ten ASCII digits; anything else fails the build:
+///
+/// ```
+/// const ADMIN: inin::NationalId = inin::national_id!("0040010007");
+/// assert_eq!(ADMIN.as_str(), "0040010007");
+/// ```
+///
+/// ```compile_fail
+/// let id = inin::national_id!("0040010008");
+/// ```
+#[macro_export]
+macro_rules! national_id {
+    ($value:literal) => {{
+        const ID: $crate::NationalId = match $crate::NationalId::from_ascii_digits($value.as_bytes()) {
+            Ok(id) => id,
+            Err(_) => panic!(concat!("invalid iranian national id literal: ", $value)),
+        };
+        ID
+    }};
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum NationalIdError {
+    InvalidLength(usize),
+    NonDigitCharacter,
+    InvalidCheckDigit { expected: u8, found: u8 },
+    AllZeros,
+    RepeatedDigits,
+    /// The id is on the deny list given to [`Parser::deny`].
+    Denied,
+}
+
+impl Error for NationalIdError {}
+
+impl Display for NationalIdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NationalIdError::InvalidLength(len) => {
+                write!(f, "invalid iranian national id number: expected 10 digits, got {}", len)
+            }
+            NationalIdError::NonDigitCharacter => {
+                write!(f, "invalid iranian national id number: contains non-digit characters")
+            }
+            NationalIdError::InvalidCheckDigit { expected, found } => {
+                write!(f, "invalid iranian national id number: check digit should be {}, found {}", expected, found)
+            }
+            NationalIdError::AllZeros => {
+                write!(f, "invalid iranian national id number: all digits are zero")
+            }
+            NationalIdError::RepeatedDigits => {
+                write!(f, "invalid iranian national id number: all digits are identical")
+            }
+            NationalIdError::Denied => write!(f, "invalid iranian national id number: the id is not accepted"),
+        }
+    }
+}
+
+impl LocalizedError for NationalIdError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "کد ملی نامعتبر است: ")?;
+        match self {
+            NationalIdError::InvalidLength(len) => write!(f, "باید 10 رقم باشد، {} رقم وارد شده", len),
+            NationalIdError::NonDigitCharacter => write!(f, "فقط باید شامل رقم باشد"),
+            NationalIdError::InvalidCheckDigit { expected, found } => {
+                write!(f, "رقم کنترل باید {} باشد، نه {}", expected, found)
+            }
+            NationalIdError::AllZeros => write!(f, "همه ارقام صفر هستند"),
+            NationalIdError::RepeatedDigits => write!(f, "همه ارقام یکسان هستند"),
+            NationalIdError::Denied => write!(f, "این کد پذیرفته نمی‌شود"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::fake::Rng;
+    use crate::national_id::{compute_check_digit, is_plausible, is_valid, MaskedNationalId, NationalId, NationalIdError, Parser};
+    use crate::province::Province;
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+    use std::convert::{TryFrom, TryInto};
+
+    #[test]
+    fn test_length_of_code_should_pad_to_10_digit() {
+        assert_eq!(NationalId::try_from("0451726707"), Ok(NationalId(*b"0451726707")));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(NationalId::try_from("").is_err());
+        assert!(NationalId::try_from("123").is_err());
+        assert!(NationalId::try_from("123456ab").is_err());
+        assert!(NationalId::try_from("12345678ab").is_err());
+        assert!(NationalId::try_from("a814659438").is_err());
+    }
+
+    #[test]
+    fn test_error_variants() {
+        assert_eq!(NationalId::try_from("00400100071"), Err(NationalIdError::InvalidLength(11)));
+        assert_eq!(NationalId::try_from("12345678ab"), Err(NationalIdError::NonDigitCharacter));
+        assert_eq!(NationalId::try_from(""), Err(NationalIdError::AllZeros));
+        assert_eq!(NationalId::try_from("0040010008"), Err(NationalIdError::InvalidCheckDigit { expected: 7, found: 8 }));
+    }
+
+    #[test]
+    fn test_parse_from_str() {
+        assert_eq!("0040010007".parse::<NationalId>(), NationalId::try_from("0040010007"));
+        assert!("0040010008".parse::<NationalId>().is_err());
+    }
+
+    #[test]
+    fn test_try_from_owned_and_numeric() {
+        let expected = NationalId::try_from("0040010007");
+        assert_eq!(NationalId::try_from(String::from("0040010007")), expected);
+        assert_eq!(NationalId::try_from(&String::from("0040010007")), expected);
+        assert_eq!(NationalId::try_from(40010007u64), expected);
+        assert_eq!(NationalId::try_from(10_000_000_000u64), Err(NationalIdError::InvalidLength(11)));
+    }
+
+    #[test]
+    fn test_display_canonical_form() {
+        let ni = NationalId::try_from("40010007").unwrap();
+        assert_eq!(ni.to_string(), "0040010007");
+        assert_eq!(format!("{}", ni), "0040010007");
+    }
+
+    #[test]
+    fn test_grouped_display() {
+        let ni = NationalId::try_from("0060418257").unwrap();
+        assert_eq!(ni.formatted(), "006-041825-7");
+        assert_eq!(format!("{:#}", ni), "006-041825-7");
+        assert_eq!(NationalId::parse_lenient(&ni.formatted()), Ok(ni));
+    }
+
+    #[test]
+    fn test_compare_with_str() {
+        let ni = NationalId::try_from("40010007").unwrap();
+        assert_eq!(ni, "0040010007");
+        assert_eq!("0040010007", ni);
+        assert!(ni == *"0040010007");
+        assert!(*"0040010007" == ni);
+        assert_ne!(ni, "40010007");
+        assert_ne!("0814659438", ni);
+    }
+
+    #[test]
+    fn test_borrow_as_str_key() {
+        let ni = NationalId::try_from("40010007").unwrap();
+        let mut map = HashMap::new();
+        map.insert(ni, "admin");
+        assert_eq!(map.get("0040010007"), Some(&"admin"));
+        assert_eq!(map.get("40010007"), None);
+
+        let set: BTreeSet<NationalId> = vec![ni].into_iter().collect();
+        assert!(set.contains("0040010007"));
+        assert_eq!(String::from(ni), "0040010007");
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        let ni = NationalId::try_from("0040010007").unwrap();
+        assert!(ni.ct_eq(&NationalId::try_from("40010007").unwrap()));
+        assert!(!ni.ct_eq(&NationalId::try_from("0814659438").unwrap()));
+    }
+
+    #[test]
+    fn test_to_persian_digits() {
+        let ni = NationalId::try_from("0040010007").unwrap();
+        assert_eq!(ni.to_persian_digits(), "۰۰۴۰۰۱۰۰۰۷");
+        assert_eq!(NationalId::try_from(ni.to_persian_digits().as_str()), Ok(ni));
+    }
+
+    #[test]
+    fn test_can_be_used_as_map_key() {
+        let mut set = HashSet::new();
+        set.insert(NationalId::try_from("0040010007").unwrap());
+        set.insert(NationalId::try_from("40010007").unwrap());
+        assert_eq!(set.len(), 1);
+
+        let mut map = BTreeMap::new();
+        map.insert(NationalId::try_from("0814659438").unwrap(), 2);
+        map.insert(NationalId::try_from("0040010007").unwrap(), 1);
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_accessors() {
+        let ni = NationalId::try_from("0814659438").unwrap();
+        assert_eq!(ni.as_str(), "0814659438");
+        assert_eq!(ni.as_ref() as &str, "0814659438");
+        assert_eq!(ni.into_inner(), String::from("0814659438"));
+    }
+
+    #[test]
+    fn test_persian_and_arabic_digits() {
+        let expected = NationalId::try_from("0040010007");
+        assert_eq!(NationalId::try_from("۰۰۴۰۰۱۰۰۰۷"), expected);
+        assert_eq!(NationalId::try_from("٠٠٤٠٠١٠٠٠٧"), expected);
+        assert_eq!(NationalId::try_from("۴۰۰1۰۰۰7"), expected);
+        assert_eq!("۰۸۱۴۶۵۹۴۳۸".parse::<NationalId>().unwrap().as_str(), "0814659438");
+    }
+
+    #[test]
+    fn test_lenient_parsing_strips_separators() {
+        let expected = NationalId::try_from("0814659438");
+        assert_eq!(NationalId::parse_lenient("081-465943-8"), expected);
+        assert_eq!(NationalId::parse_lenient(" 081 4659 438 "), expected);
+        assert_eq!(NationalId::parse_lenient("081\u{200C}465943\u{200B}8"), expected);
+        assert_eq!(NationalId::parse_lenient("۰۸۱–۴۶۵۹۴۳–۸"), expected);
+        assert!(NationalId::try_from("081-465943-8").is_err());
+    }
+
+    #[test]
+    fn test_strict_parsing_requires_ten_digits() {
+        assert!(NationalId::parse_strict("0040010007").is_ok());
+        assert!(NationalId::parse_strict("۰۰۴۰۰۱۰۰۰۷").is_ok());
+        assert_eq!(NationalId::parse_strict("40010007"), Err(NationalIdError::InvalidLength(8)));
+        assert_eq!(NationalId::parse_strict(" 0040010007"), Err(NationalIdError::InvalidLength(11)));
+        assert_eq!(NationalId::parse_strict(""), Err(NationalIdError::InvalidLength(0)));
+    }
+
+    #[test]
+    fn test_parser_composes_policies() {
+        assert!(Parser::new().parse("0040010007").is_ok());
+        assert!(Parser::new().parse("۰۰۴۰۰۱۰۰۰۷").is_err());
+        assert!(Parser::new().allow_persian_digits().parse("۰۰۴۰۰۱۰۰۰۷").is_ok());
+        assert!(Parser::new().parse("40010007").is_ok());
+        assert_eq!(Parser::new().strict_length().parse("40010007"), Err(NationalIdError::InvalidLength(8)));
+        assert!(Parser::new().parse("004-001000-7").is_err());
+        assert!(Parser::new().strip_separators().parse("004-001000-7").is_ok());
+        assert!(Parser::new().strict_length().strip_separators().allow_persian_digits().parse("۰۰۴-۰۰۱۰۰۰-۷").is_ok());
+    }
+
+    #[test]
+    fn test_reject_repeated_digits_is_opt_in() {
+        assert!(NationalId::try_from("1111111111").is_ok());
+        assert!(NationalId::try_from("2222222222").is_ok());
+
+        let parser = Parser::new().reject_repeated_digits();
+        assert_eq!(parser.parse("1111111111"), Err(NationalIdError::RepeatedDigits));
+        assert_eq!(parser.parse("2222222222"), Err(NationalIdError::RepeatedDigits));
+        assert!(parser.parse("0040010007").is_ok());
+    }
+
+    #[test]
+    fn test_deny_list() {
+        let denied = [NationalId::try_from("0040010007").unwrap()];
+        let parser = Parser::new().strip_separators().deny(&denied);
+        assert_eq!(parser.parse("004-001000-7"), Err(NationalIdError::Denied));
+        assert!(parser.parse("0814659438").is_ok());
+        assert_eq!(
+            NationalIdError::Denied.to_string(),
+            "invalid iranian national id number: the id is not accepted"
+        );
+    }
+
+    #[test]
+    fn test_issuance_location() {
+        let location = NationalId::try_from("0040010007").unwrap().issuance_location().unwrap();
+        assert_eq!(location.province, Province::Tehran);
+        assert_eq!(location.city, "Tehran");
+        assert_eq!(NationalId::try_from("1111111111").unwrap().issuance_location(), None);
+    }
+
+    #[test]
+    fn test_random_ids_are_valid() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            let id = NationalId::random(&mut rng);
+            assert_eq!(NationalId::parse_strict(id.as_str()), Ok(id));
+        }
+    }
+
+    #[test]
+    fn test_random_for_province() {
+        let mut rng = Rng::new(3);
+        for _ in 0..100 {
+            let id = NationalId::random_for_province(Province::Isfahan, &mut rng).unwrap();
+            assert!(NationalId::parse_strict(id.as_str()).is_ok());
+            assert_eq!(id.issuance_location().unwrap().province, Province::Isfahan);
+        }
+        for province in Province::all() {
+            let id = NationalId::random_for_province(province, &mut rng);
+            assert_eq!(id.and_then(|id| id.issuance_location()).map(|location| location.province), Some(province));
+        }
+    }
+
+    #[test]
+    fn test_random_by_population() {
+        let mut rng = Rng::new(5);
+        let provinces: Vec<Province> = (0..2000)
+            .map(|_| NationalId::random_by_population(&mut rng).issuance_location().unwrap().province)
+            .collect();
+        let count = |province| provinces.iter().filter(|p| **p == province).count();
+        assert!(count(Province::Tehran) > count(Province::Isfahan));
+        assert!(count(Province::Isfahan) > count(Province::Semnan));
+
+        let mut rng = Rng::new(6);
+        let provinces: Vec<Province> = (0..50_000)
+            .map(|_| NationalId::random_by_population(&mut rng).issuance_location().unwrap().province)
+            .collect();
+        for province in Province::all() {
+            assert!(provinces.contains(&province), "{} never drawn", province);
+        }
+    }
+
+    #[test]
+    fn test_complete_first_nine_digits() {
+        assert_eq!(NationalId::complete("004001000"), NationalId::try_from("0040010007"));
+        assert_eq!(NationalId::complete("4001000"), NationalId::try_from("0040010007"));
+        assert_eq!(NationalId::complete("۰۸۱۴۶۵۹۴۳"), NationalId::try_from("0814659438"));
+        assert_eq!(compute_check_digit("081465943"), Ok(8));
+        assert_eq!(compute_check_digit("0040010007"), Err(NationalIdError::InvalidLength(10)));
+        assert_eq!(compute_check_digit("00400a000"), Err(NationalIdError::NonDigitCharacter));
+        assert_eq!(compute_check_digit(""), Err(NationalIdError::AllZeros));
+    }
+
+    #[test]
+    fn test_is_valid_agrees_with_try_from() {
+        for input in &["0040010007", "40010007", " 0814659438 ", "۰۰۴۰۰۱۰۰۰۷", "", "123", "12345678ab",
+                       "0040010008", "00400100071", "1111111111"] {
+            assert_eq!(is_valid(input), NationalId::try_from(*input).is_ok(), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_hash_u64_is_stable() {
+        assert_eq!(NationalId::try_from("0040010007").unwrap().hash_u64(), 8_023_478_123_804_003_583);
+        assert_eq!(NationalId::try_from("0814659438").unwrap().hash_u64(), 11_351_164_352_098_217_858);
+    }
+
+    #[test]
+    fn test_iter_with_prefix() {
+        let ids: Vec<NationalId> = NationalId::iter_with_prefix("00400100").unwrap().collect();
+        assert_eq!(ids.len(), 10);
+        assert_eq!(ids[0].as_str(), "0040010007");
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+        assert!(ids.iter().all(|id| id.as_str().starts_with("00400100")));
+
+        let mut iter = NationalId::iter_with_prefix("۰۰۶").unwrap();
+        assert_eq!(iter.len(), 1_000_000);
+        assert_eq!(iter.next_back().unwrap().prefix(), "006");
+        assert_eq!(iter.nth(999_998).map(|id| id.as_str().starts_with("006")), Some(true));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(NationalId::iter_with_prefix("00000000").unwrap().len(), 9);
+        assert_eq!(NationalId::iter_with_prefix("").unwrap().len(), 999_999_999);
+        assert_eq!(NationalId::iter_with_prefix("123456789").unwrap().len(), 1);
+        assert_eq!(NationalId::iter_with_prefix("0123456789").unwrap_err(), NationalIdError::InvalidLength(10));
+        assert_eq!(NationalId::iter_with_prefix("00a").unwrap_err(), NationalIdError::NonDigitCharacter);
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let id = NationalId::try_from("0040010007").unwrap();
+        assert_eq!(NationalId::from_bytes(b"0040010007"), Ok(id));
+        assert_eq!(NationalId::try_from(&b"  40010007\r\n"[..]), Ok(id));
+        assert_eq!(NationalId::from_bytes(b"00400100071"), Err(NationalIdError::InvalidLength(11)));
+        assert_eq!(NationalId::from_bytes(b"004001000\xff"), Err(NationalIdError::NonDigitCharacter));
+        assert_eq!(NationalId::from_bytes("۰۰۴۰۰۱۰۰۰۷".as_bytes()), Err(NationalIdError::InvalidLength(20)));
+    }
+
+    #[test]
+    fn test_is_plausible() {
+        for input in &["0040010007", "0040010008", "40010007", " ۰۰۴۰۰۱۰۰۰۷ "] {
+            assert!(is_plausible(input), "{:?}", input);
+        }
+        for input in &["", "   ", "00400100071", "12345678ab", "004-001-0007"] {
+            assert!(!is_plausible(input), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_is_copy_and_compact() {
+        let id = NationalId::try_from("0040010007").unwrap();
+        let copy = id;
+        assert_eq!(id, copy);
+        assert_eq!(std::mem::size_of::<NationalId>(), 10);
+    }
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let id = NationalId::try_from("0040010007").unwrap();
+        assert_eq!(format!("{:?}", id), "NationalId(\"004*****07\")");
+        assert_eq!(format!("{:?}", Some(id)), "Some(NationalId(\"004*****07\"))");
+        assert_eq!(id.to_string(), "0040010007");
+    }
+
+    #[test]
+    fn test_u64_round_trip() {
+        let id = NationalId::try_from("0040010007").unwrap();
+        assert_eq!(id.to_u64(), 40010007);
+        assert_eq!(NationalId::from_u64(id.to_u64()), Ok(id));
+        assert_eq!(NationalId::from_u64(40010008), Err(NationalIdError::InvalidCheckDigit { expected: 7, found: 8 }));
+    }
+
+    #[test]
+    fn test_should_deref_to_string() {
+        let code = "0814659438";
+        let result: Result<NationalId, _> = code.try_into();
+        assert_eq!(code, &*result.unwrap())
+    }
+
+    #[test]
+    fn test_validate_national_id() {
+        let ni: Result<NationalId, NationalIdError> = "0040010007".try_into();
+        assert!(ni.is_ok());
+
+        let ni: Result<NationalId, NationalIdError> = "0814659438".try_into();
+        assert!(ni.is_ok());
+    }
+
+    #[test]
+    fn test_from_ascii_digits_in_const_context() {
+        const ID: Result<NationalId, NationalIdError> = NationalId::from_ascii_digits(b"0040010007");
+        assert_eq!(ID, NationalId::try_from("0040010007"));
+        assert_eq!(crate::national_id!("0814659438"), NationalId::try_from("0814659438").unwrap());
+
+        assert_eq!(NationalId::from_ascii_digits(b"40010007"), Err(NationalIdError::InvalidLength(8)));
+        assert_eq!(NationalId::from_ascii_digits(b"004001000a"), Err(NationalIdError::NonDigitCharacter));
+        assert_eq!(NationalId::from_ascii_digits(b"0000000000"), Err(NationalIdError::AllZeros));
+        assert_eq!(NationalId::from_ascii_digits(b"0040010008"), Err(NationalIdError::InvalidCheckDigit { expected: 7, found: 8 }));
+    }
+
+    #[test]
+    fn test_usable_as_cli_argument() {
+        // Argument parsers such as clap's `value_parser!` need a cloneable `FromStr` type
+        // whose error converts into a boxed, thread-safe error.
+        fn parse<T>(value: &str) -> Result<T, Box<dyn std::error::Error + Send + Sync>>
+        where
+            T: std::str::FromStr + Clone + Send + Sync + 'static,
+            T::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+        {
+            value.parse::<T>().map_err(Into::into)
+        }
+
+        assert!(parse::<NationalId>("0040010007").is_ok());
+        assert!(parse::<crate::Sheba>("IR062960000000100324200001").is_ok());
+        assert!(parse::<crate::PersonId>("0040010007").is_ok());
+        let err = parse::<NationalId>("0040010008").unwrap_err();
+        assert_eq!(err.to_string(), "invalid iranian national id number: check digit should be 7, found 8");
+    }
+
+    #[test]
+    fn test_masked() {
+        let id = NationalId::try_from("0060418257").unwrap();
+        assert_eq!(id.masked(), "006*****57");
+
+        let masked = MaskedNationalId::from(id);
+        assert_eq!(masked, id.to_masked());
+        assert_eq!(format!("{} {:?}", masked, masked), "006*****57 MaskedNationalId(\"006*****57\")");
+        assert_eq!(masked.unmasked(), id);
+    }
+
+    #[test]
+    fn test_new_unchecked() {
+        let id = NationalId::new_unchecked("0040010007");
+        assert_eq!(id, NationalId::try_from("0040010007").unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_unchecked_panics_on_wrong_length() {
+        NationalId::new_unchecked("40010007");
+    }
+
+    #[test]
+    fn test_digit_accessors() {
+        let id = NationalId::try_from("0814659438").unwrap();
+        assert_eq!(id.digits(), [0, 8, 1, 4, 6, 5, 9, 4, 3, 8]);
+        assert_eq!(id.check_digit(), 8);
+        assert_eq!(id.prefix(), "081");
+    }
+}
+
+/// Tests of the paths that work without `alloc`, run by `cargo test --no-default-features`.
+#[cfg(test)]
+mod core_tests {
+    use crate::national_id::{compute_check_digit, is_valid, NationalId, NationalIdError, Parser};
+    use crate::province::Province;
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_parse_without_alloc() {
+        let id = NationalId::try_from(" ۴۰۰۱۰۰۰۷ ").unwrap();
+        assert_eq!(id.as_str(), "0040010007");
+        assert_eq!(id.to_u64(), 40010007);
+        assert_eq!(NationalId::from_u64(40010007), Ok(id));
+        assert_eq!(NationalId::from_ascii_digits(b"0040010007"), Ok(id));
+        assert_eq!(NationalId::parse_lenient("004-001000-7"), Ok(id));
+        assert_eq!(NationalId::parse_strict("40010007"), Err(NationalIdError::InvalidLength(8)));
+        assert_eq!(NationalId::try_from("0040010008"), Err(NationalIdError::InvalidCheckDigit { expected: 7, found: 8 }));
+        assert_eq!(compute_check_digit("004001000"), Ok(7));
+        assert!(is_valid("0040010007") && !is_valid("0040010008"));
+        assert_eq!(id.issuance_location().map(|location| location.province), Some(Province::Tehran));
+    }
+
+    #[test]
+    fn test_parser_without_alloc() {
+        let denied = [NationalId::try_from("0814659438").unwrap()];
+        let parser = Parser::new().strip_separators().allow_persian_digits().reject_repeated_digits().deny(&denied);
+        assert!(parser.parse("۰۰۴-۰۰۱۰۰۰-۷").is_ok());
+        assert_eq!(parser.parse("1111111111"), Err(NationalIdError::RepeatedDigits));
+        assert_eq!(parser.parse("081-465943-8"), Err(NationalIdError::Denied));
+        assert_eq!(Parser::new().parse("۰۰۴۰۰۱۰۰۰۷"), Err(NationalIdError::NonDigitCharacter));
+        assert_eq!(Parser::new().strict_length().parse(" 0040010007"), Err(NationalIdError::InvalidLength(11)));
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::hash_set::{self, HashSet};
+use core::iter::FromIterator;
+
+use crate::national_id::NationalId;
+
+/// Memory-efficient set of national ids.
+///
+/// Only the first nine digits of each id are kept, packed into a `u32`; the check
+/// digit is recomputed when iterating.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NationalIdSet {
+    bodies: HashSet<u32>,
+}
+
+impl NationalIdSet {
+    pub fn new() -> Self {
+        NationalIdSet::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        NationalIdSet { bodies: HashSet::with_capacity(capacity) }
+    }
+
+    /// Adds `id`, returning `false` if it was already present.
+    pub fn insert(&mut self, id: NationalId) -> bool {
+        self.bodies.insert(id.body())
+    }
+
+    pub fn contains(&self, id: &NationalId) -> bool {
+        self.bodies.contains(&id.body())
+    }
+
+    pub fn remove(&mut self, id: &NationalId) -> bool {
+        self.bodies.remove(&id.body())
+    }
+
+    pub fn len(&self) -> usize {
+        self.bodies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bodies.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.bodies.clear()
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        Iter(self.bodies.iter())
+    }
+}
+
+pub struct Iter<'a>(hash_set::Iter<'a, u32>);
+
+impl Iterator for Iter<'_> {
+    type Item = NationalId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|body| NationalId::from_body(*body))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a> IntoIterator for &'a NationalIdSet {
+    type Item = NationalId;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Extend<NationalId> for NationalIdSet {
+    fn extend<T: IntoIterator<Item = NationalId>>(&mut self, iter: T) {
+        self.bodies.extend(iter.into_iter().map(|id| id.body()))
+    }
+}
+
+impl FromIterator<NationalId> for NationalIdSet {
+    fn from_iter<T: IntoIterator<Item = NationalId>>(iter: T) -> Self {
+        let mut set = NationalIdSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fake;
+    use crate::national_id::NationalId;
+    use crate::national_id_set::NationalIdSet;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_insert_contains_remove() {
+        let id = NationalId::try_from("0040010007").unwrap();
+        let mut set = NationalIdSet::new();
+        assert!(set.insert(id));
+        assert!(!set.insert(NationalId::try_from("40010007").unwrap()));
+        assert!(set.contains(&id));
+        assert!(!set.contains(&NationalId::try_from("0814659438").unwrap()));
+        assert_eq!(set.len(), 1);
+        assert!(set.remove(&id));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_iterates_original_ids() {
+        let ids = fake::national_ids(9, 500);
+        let set: NationalIdSet = ids.iter().copied().collect();
+        let mut from_set: Vec<NationalId> = set.iter().collect();
+        let mut expected = ids.clone();
+        from_set.sort();
+        expected.sort();
+        expected.dedup();
+        assert_eq!(from_set, expected);
+    }
+}
@@ -0,0 +1,66 @@
+pub(crate) fn normalize_digit(c: char) -> char {
+    match c {
+        '\u{06F0}'..='\u{06F9}' => (b'0' + (c as u32 - 0x06F0) as u8) as char,
+        '\u{0660}'..='\u{0669}' => (b'0' + (c as u32 - 0x0660) as u8) as char,
+        _ => c,
+    }
+}
+
+/// The Persian digit (۰–۹) for an ASCII digit; any other character is returned as is.
+pub(crate) fn persian_digit(c: char) -> char {
+    match c.to_digit(10) {
+        Some(d) => char::from_u32(0x06F0 + d).unwrap(),
+        None => c,
+    }
+}
+
+/// Replaces ASCII digits with Persian ones (۰–۹), leaving every other character as is.
+#[cfg(feature = "alloc")]
+pub(crate) fn to_persian_digits(value: &str) -> alloc::string::String {
+    value.chars().map(persian_digit).collect()
+}
+
+pub(crate) fn is_separator(c: char) -> bool {
+    c.is_whitespace()
+        || matches!(c, '-' | '\u{2010}'..='\u{2015}' | '\u{2212}')
+        || matches!(c, '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}')
+}
+
+/// Why [`digits`] rejected its input.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DigitsError {
+    /// The input had this many characters instead of the expected number.
+    Length(usize),
+    NonDigit,
+}
+
+/// The values of exactly `N` digits, normalizing Persian and Arabic-Indic ones, without
+/// allocating. The length is checked before the characters.
+#[cfg(feature = "alloc")]
+pub(crate) fn digits<const N: usize>(chars: impl Iterator<Item = char>) -> Result<[u32; N], DigitsError> {
+    let mut digits = [0; N];
+    let mut len = 0;
+    let mut non_digit = false;
+    for c in chars {
+        match normalize_digit(c).to_digit(10) {
+            Some(d) if len < N => digits[len] = d,
+            Some(_) => {}
+            None => non_digit = true,
+        }
+        len += 1;
+    }
+    if len != N {
+        return Err(DigitsError::Length(len));
+    }
+    if non_digit {
+        return Err(DigitsError::NonDigit);
+    }
+    Ok(digits)
+}
+
+/// The ASCII string of digit values returned by [`digits`].
+#[cfg(feature = "alloc")]
+pub(crate) fn digits_to_string(digits: &[u32]) -> alloc::string::String {
+    digits.iter().map(|d| char::from_digit(*d, 10).unwrap()).collect()
+}
@@ -0,0 +1,68 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+use crate::national_id::{NationalId, NationalIdError};
+
+/// Letters and symbols commonly misread by OCR in place of a digit.
+const CONFUSIONS: [(char, char); 16] = [
+    ('O', '0'), ('o', '0'), ('D', '0'), ('Q', '0'),
+    ('I', '1'), ('l', '1'), ('i', '1'), ('|', '1'),
+    ('Z', '2'), ('z', '2'),
+    ('S', '5'), ('s', '5'),
+    ('G', '6'), ('b', '6'),
+    ('B', '8'),
+    ('g', '9'),
+];
+
+/// A character replaced by [`NationalId::parse_ocr`](crate::NationalId::parse_ocr), with its
+/// index (in characters) in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OcrCorrection {
+    pub position: usize,
+    pub from: char,
+    pub to: char,
+}
+
+impl Display for OcrCorrection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?} at position {} read as {}", self.from, self.position, self.to)
+    }
+}
+
+pub(crate) fn parse(value: &str) -> Result<(NationalId, Vec<OcrCorrection>), NationalIdError> {
+    let mut corrections = Vec::new();
+    let corrected: String = value.chars().enumerate()
+        .map(|(position, c)| match CONFUSIONS.iter().find(|(from, _)| *from == c) {
+            Some((from, to)) => {
+                corrections.push(OcrCorrection { position, from: *from, to: *to });
+                *to
+            }
+            None => c,
+        })
+        .collect();
+
+    let id = NationalId::parse_lenient(&corrected)?;
+    Ok((id, corrections))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::national_id::{NationalId, NationalIdError};
+    use crate::ocr::OcrCorrection;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_parse_ocr_corrections() {
+        let (id, corrections) = NationalId::parse_ocr("OO4-OOlOOO-7").unwrap();
+        assert_eq!(id, NationalId::try_from("0040010007").unwrap());
+        assert_eq!(corrections.len(), 8);
+        assert_eq!(corrections[4], OcrCorrection { position: 6, from: 'l', to: '1' });
+        assert_eq!(corrections[4].to_string(), "'l' at position 6 read as 1");
+
+        let (_, corrections) = NationalId::parse_ocr("0814659438").unwrap();
+        assert!(corrections.is_empty());
+        assert_eq!(NationalId::parse_ocr("OO4OOlOOO8"), Err(NationalIdError::InvalidCheckDigit { expected: 7, found: 8 }));
+        assert_eq!(NationalId::parse_ocr("0O4OOlOOOx"), Err(NationalIdError::NonDigitCharacter));
+    }
+}
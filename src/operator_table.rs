@@ -0,0 +1,193 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+use crate::json;
+use crate::mobile_number::{MobileNumber, Operator, OPERATOR_PREFIXES};
+
+/// A mobile prefix allocation table loaded at runtime, for when the bundled snapshot used
+/// by [`MobileNumber::operator`] is out of date, e.g. after an MVNO gets a new range.
+///
+/// Prefixes are the leading digits of the local `09…` form, matched longest first, so
+/// `09981` takes precedence over `0998`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorTable {
+    entries: Vec<(String, Operator)>,
+}
+
+impl OperatorTable {
+    /// A copy of the bundled snapshot, to extend or override.
+    pub fn bundled() -> Self {
+        let entries = OPERATOR_PREFIXES.iter().map(|(prefix, operator)| (prefix.to_string(), *operator)).collect();
+        OperatorTable { entries }
+    }
+
+    /// Parses `prefix,operator` lines, e.g. `09981,Shatel Mobile`.
+    ///
+    /// Operators are parsed like [`Operator::from_str`](core::str::FromStr). Blank lines,
+    /// lines starting with `#` and a leading `prefix,operator` header are skipped. Later
+    /// lines override earlier ones for the same prefix.
+    pub fn from_csv(text: &str) -> Result<Self, OperatorTableError> {
+        let mut table = OperatorTable { entries: Vec::new() };
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || (i == 0 && line.eq_ignore_ascii_case("prefix,operator")) {
+                continue;
+            }
+
+            let number = i + 1;
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let (prefix, operator) = match fields[..] {
+                [prefix, operator] => (prefix, operator),
+                _ => return Err(OperatorTableError::InvalidLine(number)),
+            };
+            table.insert_parsed(prefix, operator, number)?;
+        }
+        Ok(table)
+    }
+
+    /// Parses a JSON object mapping prefixes to operators, e.g.
+    /// `{"09981": "Shatel Mobile", "0999": "آپتل"}`. Later keys override earlier ones.
+    pub fn from_json(text: &str) -> Result<Self, OperatorTableError> {
+        let mut table = OperatorTable { entries: Vec::new() };
+        for (line, prefix, operator) in json::string_object(text).map_err(OperatorTableError::InvalidJson)? {
+            table.insert_parsed(&prefix, &operator, line)?;
+        }
+        Ok(table)
+    }
+
+    /// Reads a table in the format of [`OperatorTable::from_csv`].
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: std::io::Read>(mut reader: R) -> Result<Self, OperatorTableError> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text).map_err(|err| OperatorTableError::Io(err.kind()))?;
+        OperatorTable::from_csv(&text)
+    }
+
+    fn insert_parsed(&mut self, prefix: &str, operator: &str, line: usize) -> Result<(), OperatorTableError> {
+        let valid = prefix.starts_with("09") && (3..=10).contains(&prefix.len()) && prefix.bytes().all(|b| b.is_ascii_digit());
+        if !valid {
+            return Err(OperatorTableError::InvalidPrefix(line));
+        }
+        let operator = operator.parse().map_err(|_| OperatorTableError::UnknownOperator(line))?;
+        self.insert(prefix, operator);
+        Ok(())
+    }
+
+    fn insert(&mut self, prefix: &str, operator: Operator) {
+        match self.entries.iter_mut().find(|(p, _)| p == prefix) {
+            Some(entry) => entry.1 = operator,
+            None => self.entries.push((prefix.to_string(), operator)),
+        }
+    }
+
+    /// Adds the entries of `other`, replacing those with the same prefix; e.g. to apply
+    /// updated allocations on top of [`OperatorTable::bundled`].
+    pub fn extend(&mut self, other: &OperatorTable) {
+        for (prefix, operator) in &other.entries {
+            self.insert(prefix, *operator);
+        }
+    }
+
+    /// The operator the longest matching prefix is allocated to.
+    pub fn operator(&self, number: &MobileNumber) -> Option<Operator> {
+        self.entries.iter()
+            .filter(|(prefix, _)| number.as_str().starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, operator)| *operator)
+    }
+
+    /// Prefixes allocated to `operator`, in table order.
+    pub fn prefixes_of(&self, operator: Operator) -> impl Iterator<Item = &str> {
+        self.entries.iter().filter(move |(_, o)| *o == operator).map(|(prefix, _)| prefix.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Why an operator table could not be loaded; line numbers start at 1.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OperatorTableError {
+    InvalidLine(usize),
+    InvalidPrefix(usize),
+    UnknownOperator(usize),
+    InvalidJson(usize),
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+impl Error for OperatorTableError {}
+
+impl Display for OperatorTableError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            OperatorTableError::InvalidLine(line) => {
+                write!(f, "invalid operator table: line {} is not prefix,operator", line)
+            }
+            OperatorTableError::InvalidPrefix(line) => {
+                write!(f, "invalid operator table: line {} does not have a 09 prefix of up to 10 digits", line)
+            }
+            OperatorTableError::UnknownOperator(line) => {
+                write!(f, "invalid operator table: unknown operator on line {}", line)
+            }
+            OperatorTableError::InvalidJson(line) => {
+                write!(f, "invalid operator table: line {} is not part of a json object of strings", line)
+            }
+            #[cfg(feature = "std")]
+            OperatorTableError::Io(kind) => write!(f, "could not read operator table: {}", kind),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::mobile_number::{MobileNumber, Operator};
+    use crate::operator_table::{OperatorTable, OperatorTableError};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_load_table() {
+        let csv = "prefix,operator\n# new shatel range\n09982,Shatel Mobile\n\n0999,آپتل\n";
+        let table = OperatorTable::from_csv(csv).unwrap();
+        assert_eq!(table.len(), 2);
+
+        let number = MobileNumber::try_from("09982345678").unwrap();
+        assert_eq!(number.operator(), None);
+        assert_eq!(number.operator_in(&table), Some(Operator::ShatelMobile));
+        assert_eq!(table.prefixes_of(Operator::Aptel).collect::<Vec<_>>(), vec!["0999"]);
+        assert_eq!(OperatorTable::from_reader(csv.as_bytes()), Ok(table.clone()));
+        assert_eq!(OperatorTable::from_json(r#"{"09982": "shatel mobile", "0999": "Aptel"}"#), Ok(table));
+    }
+
+    #[test]
+    fn test_bundled_copy_and_overrides() {
+        let mut table = OperatorTable::bundled();
+        let number = MobileNumber::try_from("09991234567").unwrap();
+        assert_eq!(number.operator_in(&table), Some(Operator::Aptel));
+
+        table.extend(&OperatorTable::from_csv("09991,Samantel\n0997,MCI").unwrap());
+        assert_eq!(number.operator_in(&table), Some(Operator::Samantel));
+        assert_eq!(MobileNumber::try_from("09971234567").unwrap().operator_in(&table), Some(Operator::Mci));
+        assert_eq!(table.len(), OperatorTable::bundled().len() + 1);
+    }
+
+    #[test]
+    fn test_invalid_table() {
+        assert_eq!(OperatorTable::from_csv("0912"), Err(OperatorTableError::InvalidLine(1)));
+        assert_eq!(OperatorTable::from_csv("\n0812,MCI"), Err(OperatorTableError::InvalidPrefix(2)));
+        assert_eq!(OperatorTable::from_csv("0912,Atlantis Mobile"), Err(OperatorTableError::UnknownOperator(1)));
+        assert_eq!(OperatorTable::from_json("{\"0912\": 1}"), Err(OperatorTableError::InvalidJson(1)));
+        assert_eq!("همراه اول".parse(), Ok(Operator::Mci));
+        assert_eq!(
+            OperatorTableError::UnknownOperator(3).to_string(),
+            "invalid operator table: unknown operator on line 3"
+        );
+    }
+}
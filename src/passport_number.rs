@@ -0,0 +1,164 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::locale::LocalizedError;
+use crate::normalize::{is_separator, normalize_digit, to_persian_digits};
+
+/// Iranian passport number: a Latin letter followed by 8 digits, e.g. `K12345678`.
+///
+/// The letter is upper-cased and Persian digits are accepted. The number has no published
+/// check digit, so only its shape is validated.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct PassportNumber(String);
+
+impl TryFrom<&str> for PassportNumber {
+    type Error = PassportNumberError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let chars: Vec<char> = value.chars()
+            .filter(|c| !is_separator(*c))
+            .map(|c| normalize_digit(c).to_ascii_uppercase())
+            .collect();
+        if chars.len() != 9 {
+            return Err(PassportNumberError::InvalidLength(chars.len()));
+        }
+        if !chars[0].is_ascii_uppercase() {
+            return Err(PassportNumberError::InvalidPrefix(chars[0]));
+        }
+        if !chars[1..].iter().all(|c| c.is_ascii_digit()) {
+            return Err(PassportNumberError::NonDigitCharacter);
+        }
+        if chars[1..].iter().all(|c| *c == '0') {
+            return Err(PassportNumberError::AllZeros);
+        }
+        Ok(PassportNumber(chars.into_iter().collect()))
+    }
+}
+
+impl TryFrom<String> for PassportNumber {
+    type Error = PassportNumberError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        PassportNumber::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for PassportNumber {
+    type Error = PassportNumberError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        PassportNumber::try_from(value.as_str())
+    }
+}
+
+impl FromStr for PassportNumber {
+    type Err = PassportNumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PassportNumber::try_from(s)
+    }
+}
+
+impl PassportNumber {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The number with its digits in Persian; the letter is kept.
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    /// The leading letter.
+    pub fn prefix(&self) -> char {
+        self.0.as_bytes()[0] as char
+    }
+
+    /// The 8 digits after the letter.
+    pub fn serial(&self) -> &str {
+        &self.0[1..]
+    }
+}
+
+impl AsRef<str> for PassportNumber {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for PassportNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum PassportNumberError {
+    InvalidLength(usize),
+    InvalidPrefix(char),
+    NonDigitCharacter,
+    AllZeros,
+}
+
+impl Error for PassportNumberError {}
+
+impl Display for PassportNumberError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PassportNumberError::InvalidLength(len) => {
+                write!(f, "invalid passport number: expected 9 characters, got {}", len)
+            }
+            PassportNumberError::InvalidPrefix(c) => {
+                write!(f, "invalid passport number: should start with a latin letter, found `{}`", c)
+            }
+            PassportNumberError::NonDigitCharacter => {
+                write!(f, "invalid passport number: the letter should be followed by digits only")
+            }
+            PassportNumberError::AllZeros => write!(f, "invalid passport number: all digits are zero"),
+        }
+    }
+}
+
+impl LocalizedError for PassportNumberError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "شماره گذرنامه نامعتبر است: ")?;
+        match self {
+            PassportNumberError::InvalidLength(len) => write!(f, "باید ۹ نویسه باشد، {} نویسه وارد شده", len),
+            PassportNumberError::InvalidPrefix(c) => write!(f, "باید با یک حرف لاتین شروع شود، «{}» وارد شده", c),
+            PassportNumberError::NonDigitCharacter => write!(f, "پس از حرف فقط باید رقم بیاید"),
+            PassportNumberError::AllZeros => write!(f, "همه ارقام صفر است"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::passport_number::{PassportNumber, PassportNumberError};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_validate_passport_number() {
+        let passport = PassportNumber::try_from(" k۱۲۳۴ ۵۶۷۸ ").unwrap();
+        assert_eq!(passport.as_str(), "K12345678");
+        assert_eq!((passport.prefix(), passport.serial()), ('K', "12345678"));
+        assert_eq!(passport.to_persian_digits(), "K۱۲۳۴۵۶۷۸");
+        assert_eq!("E98765432".parse::<PassportNumber>().unwrap().to_string(), "E98765432");
+    }
+
+    #[test]
+    fn test_invalid_passport_number() {
+        assert_eq!(PassportNumber::try_from("K1234567"), Err(PassportNumberError::InvalidLength(8)));
+        assert_eq!(PassportNumber::try_from("912345678"), Err(PassportNumberError::InvalidPrefix('9')));
+        assert_eq!(PassportNumber::try_from("ک12345678"), Err(PassportNumberError::InvalidPrefix('ک')));
+        assert_eq!(PassportNumber::try_from("KK2345678"), Err(PassportNumberError::NonDigitCharacter));
+        assert_eq!(PassportNumber::try_from("K00000000"), Err(PassportNumberError::AllZeros));
+    }
+}
@@ -0,0 +1,304 @@
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Debug, Display, Formatter};
+use core::str::FromStr;
+
+use crate::bank_card::{BankCard, BankCardError};
+#[cfg(feature = "std")]
+use crate::jalali;
+use crate::locale::LocalizedError;
+use crate::normalize::normalize_digit;
+
+/// Expiry month of a card in the Shamsi calendar, as printed on Iranian cards (`1405/06`
+/// or `05/06`, year first).
+#[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct Expiry {
+    year: u16,
+    month: u8,
+}
+
+impl Expiry {
+    pub fn new(year: u16, month: u8) -> Result<Self, ExpiryError> {
+        if !(1..=12).contains(&month) {
+            return Err(ExpiryError::InvalidMonth(month));
+        }
+        Ok(Expiry { year, month })
+    }
+
+    /// The current Shamsi month in Iran.
+    #[cfg(feature = "std")]
+    pub fn current() -> Self {
+        let (year, month, _) = jalali::today();
+        Expiry { year: year as u16, month: month as u8 }
+    }
+
+    /// The four-digit Shamsi year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+}
+
+/// Two-digit years are taken to be in the 1400s.
+impl TryFrom<&str> for Expiry {
+    type Error = ExpiryError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value: String = value.chars().filter(|c| !c.is_whitespace()).map(normalize_digit).collect();
+        let (year, month) = value.split_once(['/', '-']).ok_or(ExpiryError::InvalidFormat)?;
+        let all_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        if !all_digits(year) || !all_digits(month) || month.len() > 2 {
+            return Err(ExpiryError::InvalidFormat);
+        }
+
+        let year = match year.len() {
+            2 => 1400 + year.parse::<u16>().unwrap(),
+            4 => year.parse().unwrap(),
+            _ => return Err(ExpiryError::InvalidFormat),
+        };
+        Expiry::new(year, month.parse().unwrap())
+    }
+}
+
+impl FromStr for Expiry {
+    type Err = ExpiryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Expiry::try_from(s)
+    }
+}
+
+/// The four-digit form, e.g. `1405/06`.
+impl Display for Expiry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:04}/{:02}", self.year, self.month)
+    }
+}
+
+/// Card details entered in a checkout form: the card number, its expiry and its CVV2.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PaymentCard {
+    card: BankCard,
+    expiry: Expiry,
+    cvv2: String,
+}
+
+impl PaymentCard {
+    /// Validates the three fields together, rejecting cards that expired before the
+    /// current Shamsi month.
+    #[cfg(feature = "std")]
+    pub fn new(card: &str, expiry: &str, cvv2: &str) -> Result<Self, PaymentCardError> {
+        PaymentCard::new_at(card, expiry, cvv2, Expiry::current())
+    }
+
+    /// Like [`PaymentCard::new`], with `today` as the current month.
+    pub fn new_at(card: &str, expiry: &str, cvv2: &str, today: Expiry) -> Result<Self, PaymentCardError> {
+        let card = BankCard::try_from(card);
+        let expiry = Expiry::try_from(expiry).and_then(|expiry| {
+            if expiry < today { Err(ExpiryError::Expired) } else { Ok(expiry) }
+        });
+        let cvv2 = parse_cvv2(cvv2);
+
+        match (card, expiry, cvv2) {
+            (Ok(card), Ok(expiry), Ok(cvv2)) => Ok(PaymentCard { card, expiry, cvv2 }),
+            (card, expiry, cvv2) => Err(PaymentCardError {
+                card: card.err(),
+                expiry: expiry.err(),
+                cvv2: cvv2.err(),
+            }),
+        }
+    }
+
+    pub fn card(&self) -> &BankCard {
+        &self.card
+    }
+
+    pub fn expiry(&self) -> Expiry {
+        self.expiry
+    }
+
+    pub fn cvv2(&self) -> &str {
+        &self.cvv2
+    }
+}
+
+fn parse_cvv2(value: &str) -> Result<String, Cvv2Error> {
+    let value: String = value.trim().chars().map(normalize_digit).collect();
+    if !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Cvv2Error::NonDigitCharacter);
+    }
+    let len = value.chars().count();
+    if !(3..=4).contains(&len) {
+        return Err(Cvv2Error::InvalidLength(len));
+    }
+    Ok(value)
+}
+
+/// Masks the card number and hides the CVV2.
+impl Debug for PaymentCard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PaymentCard")
+            .field("card", &self.card)
+            .field("expiry", &self.expiry)
+            .field("cvv2", &"***")
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum ExpiryError {
+    InvalidFormat,
+    InvalidMonth(u8),
+    Expired,
+}
+
+impl Error for ExpiryError {}
+
+impl Display for ExpiryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ExpiryError::InvalidFormat => write!(f, "invalid card expiry: expected year/month, e.g. 1405/06"),
+            ExpiryError::InvalidMonth(month) => write!(f, "invalid card expiry: {} is not a month", month),
+            ExpiryError::Expired => write!(f, "invalid card expiry: the card has expired"),
+        }
+    }
+}
+
+impl LocalizedError for ExpiryError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "تاریخ انقضای کارت نامعتبر است: ")?;
+        match self {
+            ExpiryError::InvalidFormat => write!(f, "باید به شکل سال/ماه باشد، مثلاً 1405/06"),
+            ExpiryError::InvalidMonth(month) => write!(f, "{} ماه معتبری نیست", month),
+            ExpiryError::Expired => write!(f, "کارت منقضی شده است"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum Cvv2Error {
+    InvalidLength(usize),
+    NonDigitCharacter,
+}
+
+impl Error for Cvv2Error {}
+
+impl Display for Cvv2Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Cvv2Error::InvalidLength(len) => write!(f, "invalid cvv2: expected 3 or 4 digits, got {}", len),
+            Cvv2Error::NonDigitCharacter => write!(f, "invalid cvv2: contains non-digit characters"),
+        }
+    }
+}
+
+impl LocalizedError for Cvv2Error {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "کد CVV2 نامعتبر است: ")?;
+        match self {
+            Cvv2Error::InvalidLength(len) => write!(f, "باید 3 یا 4 رقم باشد، {} رقم وارد شده", len),
+            Cvv2Error::NonDigitCharacter => write!(f, "فقط باید شامل رقم باشد"),
+        }
+    }
+}
+
+/// The error of each invalid field; at least one is set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaymentCardError {
+    pub card: Option<BankCardError>,
+    pub expiry: Option<ExpiryError>,
+    pub cvv2: Option<Cvv2Error>,
+}
+
+impl PaymentCardError {
+    fn errors(&self) -> impl Iterator<Item = &dyn LocalizedError> {
+        let card = self.card.as_ref().map(|err| err as &dyn LocalizedError);
+        let expiry = self.expiry.as_ref().map(|err| err as &dyn LocalizedError);
+        let cvv2 = self.cvv2.as_ref().map(|err| err as &dyn LocalizedError);
+        card.into_iter().chain(expiry).chain(cvv2)
+    }
+}
+
+impl Error for PaymentCardError {}
+
+/// The field errors separated by `; `.
+impl Display for PaymentCardError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for (i, err) in self.errors().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", err)?;
+        }
+        Ok(())
+    }
+}
+
+impl LocalizedError for PaymentCardError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for (i, err) in self.errors().enumerate() {
+            if i > 0 {
+                write!(f, "؛ ")?;
+            }
+            err.fmt_persian(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::bank_card::BankCardError;
+    use crate::payment_card::{Cvv2Error, Expiry, ExpiryError, PaymentCard};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_expiry() {
+        assert_eq!(Expiry::try_from("1405/06"), Expiry::new(1405, 6));
+        assert_eq!(Expiry::try_from("05/6"), Expiry::new(1405, 6));
+        assert_eq!(Expiry::try_from("۰۵ / ۰۶"), Expiry::new(1405, 6));
+        assert_eq!(Expiry::new(1405, 6).unwrap().to_string(), "1405/06");
+        assert_eq!(Expiry::try_from("1405/13"), Err(ExpiryError::InvalidMonth(13)));
+        assert_eq!(Expiry::try_from("140506"), Err(ExpiryError::InvalidFormat));
+        assert_eq!(Expiry::try_from("5/06"), Err(ExpiryError::InvalidFormat));
+    }
+
+    #[test]
+    fn test_payment_card() {
+        let today = Expiry::new(1405, 7).unwrap();
+        let card = PaymentCard::new_at("6219-8610-3452-9007", "05/07", "123", today).unwrap();
+        assert_eq!(card.card().as_str(), "6219861034529007");
+        assert_eq!(card.expiry(), today);
+        assert_eq!(card.cvv2(), "123");
+        assert_eq!(
+            format!("{:?}", card),
+            "PaymentCard { card: BankCard(\"6219-86**-****-9007\"), expiry: Expiry { year: 1405, month: 7 }, cvv2: \"***\" }"
+        );
+    }
+
+    #[test]
+    fn test_reports_every_invalid_field() {
+        let today = Expiry::new(1405, 7).unwrap();
+        let err = PaymentCard::new_at("6219861034529008", "1405/06", "12", today).unwrap_err();
+        assert_eq!(err.card, Some(BankCardError::InvalidChecksum));
+        assert_eq!(err.expiry, Some(ExpiryError::Expired));
+        assert_eq!(err.cvv2, Some(Cvv2Error::InvalidLength(2)));
+        assert_eq!(
+            err.to_string(),
+            "invalid bank card number: luhn checksum mismatch; invalid card expiry: the card has expired; \
+             invalid cvv2: expected 3 or 4 digits, got 2"
+        );
+
+        let err = PaymentCard::new_at("6219861034529007", "1405/07", "12a", today).unwrap_err();
+        assert_eq!((err.card, err.expiry, err.cvv2), (None, None, Some(Cvv2Error::NonDigitCharacter)));
+    }
+
+    #[test]
+    fn test_current_expiry() {
+        assert!(Expiry::current().year() >= 1405);
+    }
+}
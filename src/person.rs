@@ -0,0 +1,148 @@
+use core::convert::TryFrom;
+
+use crate::fields::{FieldError, FieldErrors};
+use crate::jalali_date::JalaliDate;
+use crate::mobile_number::MobileNumber;
+use crate::national_id::NationalId;
+use crate::postal_code::PostalCode;
+
+/// The identity fields of a registration form, validated together.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Person {
+    pub national_id: NationalId,
+    pub mobile_number: MobileNumber,
+    pub postal_code: PostalCode,
+    pub birth_date: JalaliDate,
+}
+
+impl Person {
+    pub fn builder<'a>() -> PersonBuilder<'a> {
+        PersonBuilder::default()
+    }
+}
+
+/// Collects the raw field values of a [`Person`]; every field is required.
+///
+/// Errors are keyed by the field names `national_id`, `mobile_number`, `postal_code`
+/// and `birth_date`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PersonBuilder<'a> {
+    national_id: Option<&'a str>,
+    mobile_number: Option<&'a str>,
+    postal_code: Option<&'a str>,
+    birth_date: Option<&'a str>,
+}
+
+impl<'a> PersonBuilder<'a> {
+    pub fn national_id(mut self, value: &'a str) -> Self {
+        self.national_id = Some(value);
+        self
+    }
+
+    pub fn mobile_number(mut self, value: &'a str) -> Self {
+        self.mobile_number = Some(value);
+        self
+    }
+
+    pub fn postal_code(mut self, value: &'a str) -> Self {
+        self.postal_code = Some(value);
+        self
+    }
+
+    /// A Jalali date such as `1370/05/12`; it must not be after today.
+    pub fn birth_date(mut self, value: &'a str) -> Self {
+        self.birth_date = Some(value);
+        self
+    }
+
+    /// Validates every field, returning the errors of all invalid ones.
+    #[cfg(feature = "std")]
+    pub fn build(&self) -> Result<Person, FieldErrors> {
+        self.build_at(JalaliDate::today())
+    }
+
+    /// Like [`PersonBuilder::build`], with `today` as the current date.
+    pub fn build_at(&self, today: JalaliDate) -> Result<Person, FieldErrors> {
+        let mut errors = FieldErrors::default();
+
+        let national_id = errors.check("national_id", self.national_id, |value| {
+            NationalId::try_from(value).map_err(|err| FieldError::Invalid(err.into()))
+        });
+        let mobile_number = errors.check("mobile_number", self.mobile_number, |value| {
+            MobileNumber::try_from(value).map_err(|err| FieldError::Invalid(err.into()))
+        });
+        let postal_code = errors.check("postal_code", self.postal_code, |value| {
+            PostalCode::try_from(value).map_err(|err| FieldError::Invalid(err.into()))
+        });
+        let birth_date = errors.check("birth_date", self.birth_date, |value| {
+            match JalaliDate::try_from(value).map_err(FieldError::InvalidDate)? {
+                date if date > today => Err(FieldError::FutureDate),
+                date => Ok(date),
+            }
+        });
+
+        match (national_id, mobile_number, postal_code, birth_date) {
+            (Some(national_id), Some(mobile_number), Some(postal_code), Some(birth_date)) => {
+                Ok(Person { national_id, mobile_number, postal_code, birth_date })
+            }
+            _ => Err(errors),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::fields::FieldError;
+    use crate::jalali_date::JalaliDate;
+    use crate::locale::{Locale, LocalizedError};
+    use crate::national_id::{NationalId, NationalIdError};
+    use crate::person::Person;
+    use std::convert::TryFrom;
+
+    fn today() -> JalaliDate {
+        JalaliDate::new(1405, 7, 22).unwrap()
+    }
+
+    #[test]
+    fn test_valid_person() {
+        let person = Person::builder()
+            .national_id("۰۰۴۰۰۱۰۰۰۷")
+            .mobile_number("+98 912 345 6789")
+            .postal_code("16197-35744")
+            .birth_date("1370/05/12")
+            .build_at(today())
+            .unwrap();
+        assert_eq!(person.national_id, NationalId::try_from("0040010007").unwrap());
+        assert_eq!(person.mobile_number.as_str(), "09123456789");
+        assert_eq!(person.postal_code.as_str(), "1619735744");
+        assert_eq!(person.birth_date.to_string(), "1370/05/12");
+    }
+
+    #[test]
+    fn test_reports_every_invalid_field() {
+        let errors = Person::builder()
+            .national_id("0040010008")
+            .postal_code("1619735744")
+            .birth_date("1406/01/01")
+            .build_at(today())
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 3);
+        assert_eq!(
+            errors.get("national_id"),
+            Some(&FieldError::Invalid(NationalIdError::InvalidCheckDigit { expected: 7, found: 8 }.into()))
+        );
+        assert_eq!(errors.get("mobile_number"), Some(&FieldError::Missing));
+        assert_eq!(errors.get("birth_date"), Some(&FieldError::FutureDate));
+        assert_eq!(
+            errors.to_string(),
+            "birth_date: must not be in the future; mobile_number: required; \
+             national_id: invalid iranian national id number: check digit should be 7, found 8"
+        );
+        assert_eq!(
+            errors.to_localized_string(Locale::Fa),
+            "birth_date: نباید در آینده باشد؛ mobile_number: الزامی است؛ \
+             national_id: کد ملی نامعتبر است: رقم کنترل باید ۷ باشد، نه ۸"
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::foreign_national_id::{ForeignNationalId, ForeignNationalIdError};
+use crate::locale::LocalizedError;
+use crate::national_id::{NationalId, NationalIdError};
+use crate::normalize::{is_separator, to_persian_digits};
+
+/// Identifier of a person: a national id for citizens or a foreign nationals code for residents.
+///
+/// Twelve-digit input is parsed as a [`ForeignNationalId`], anything else as a [`NationalId`].
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub enum PersonId {
+    Citizen(NationalId),
+    Foreign(ForeignNationalId),
+}
+
+impl TryFrom<&str> for PersonId {
+    type Error = PersonIdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if value.chars().filter(|c| !is_separator(*c)).count() == 12 {
+            return ForeignNationalId::try_from(value).map(PersonId::Foreign).map_err(PersonIdError::Foreign);
+        }
+        NationalId::try_from(value).map(PersonId::Citizen).map_err(PersonIdError::Citizen)
+    }
+}
+
+impl FromStr for PersonId {
+    type Err = PersonIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PersonId::try_from(s)
+    }
+}
+
+impl PersonId {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PersonId::Citizen(id) => id.as_str(),
+            PersonId::Foreign(id) => id.as_str(),
+        }
+    }
+
+    /// The id written with Persian digits, whichever kind it is.
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+}
+
+impl Display for PersonId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum PersonIdError {
+    Citizen(NationalIdError),
+    Foreign(ForeignNationalIdError),
+}
+
+impl Error for PersonIdError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PersonIdError::Citizen(err) => Some(err),
+            PersonIdError::Foreign(err) => Some(err),
+        }
+    }
+}
+
+impl Display for PersonIdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PersonIdError::Citizen(_) => write!(f, "invalid person id: not a valid national id"),
+            PersonIdError::Foreign(_) => write!(f, "invalid person id: not a valid foreign national id"),
+        }
+    }
+}
+
+impl LocalizedError for PersonIdError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PersonIdError::Citizen(err) => err.fmt_persian(f),
+            PersonIdError::Foreign(err) => err.fmt_persian(f),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::foreign_national_id::ForeignNationalIdError;
+    use crate::person_id::{PersonId, PersonIdError};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_tries_both_identifier_kinds() {
+        assert!(matches!(PersonId::try_from("0040010007"), Ok(PersonId::Citizen(_))));
+        assert!(matches!(PersonId::try_from("123456789012"), Ok(PersonId::Foreign(_))));
+        assert_eq!(PersonId::try_from("1234 5678 9012").unwrap().as_str(), "123456789012");
+    }
+
+    #[test]
+    fn test_reports_error_of_matching_kind() {
+        assert!(matches!(PersonId::try_from("0040010008"), Err(PersonIdError::Citizen(_))));
+        assert_eq!(
+            PersonId::try_from("111111111111"),
+            Err(PersonIdError::Foreign(ForeignNationalIdError::RepeatedDigits))
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use alloc::string::{String, ToString};
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::fake::Rng;
+use crate::locale::LocalizedError;
+use crate::normalize::{digits, digits_to_string, is_separator, to_persian_digits, DigitsError};
+use crate::province::Province;
+
+/// Postal code prefix allocations (longest prefix wins).
+const PROVINCE_PREFIXES: [(&str, Province); 61] = [
+    ("1", Province::Tehran),
+    ("31", Province::Alborz),
+    ("33", Province::Tehran),
+    ("34", Province::Qazvin),
+    ("35", Province::Semnan),
+    ("36", Province::Semnan),
+    ("37", Province::Qom),
+    ("38", Province::Markazi),
+    ("39", Province::Markazi),
+    ("41", Province::Gilan),
+    ("42", Province::Gilan),
+    ("43", Province::Gilan),
+    ("44", Province::Gilan),
+    ("45", Province::Zanjan),
+    ("46", Province::Mazandaran),
+    ("47", Province::Mazandaran),
+    ("48", Province::Mazandaran),
+    ("49", Province::Golestan),
+    ("51", Province::EastAzerbaijan),
+    ("53", Province::EastAzerbaijan),
+    ("54", Province::EastAzerbaijan),
+    ("55", Province::EastAzerbaijan),
+    ("56", Province::Ardabil),
+    ("57", Province::WestAzerbaijan),
+    ("58", Province::WestAzerbaijan),
+    ("59", Province::WestAzerbaijan),
+    ("61", Province::Khuzestan),
+    ("63", Province::Khuzestan),
+    ("64", Province::Khuzestan),
+    ("65", Province::Hamadan),
+    ("66", Province::Kurdistan),
+    ("67", Province::Kermanshah),
+    ("68", Province::Lorestan),
+    ("69", Province::Ilam),
+    ("71", Province::Fars),
+    ("73", Province::Fars),
+    ("74", Province::Fars),
+    ("751", Province::Bushehr),
+    ("753", Province::Bushehr),
+    ("755", Province::Bushehr),
+    ("759", Province::KohgiluyehAndBoyerAhmad),
+    ("76", Province::Kerman),
+    ("77", Province::Kerman),
+    ("78", Province::Kerman),
+    ("79", Province::Hormozgan),
+    ("81", Province::Isfahan),
+    ("83", Province::Isfahan),
+    ("84", Province::Isfahan),
+    ("85", Province::Isfahan),
+    ("86", Province::Isfahan),
+    ("87", Province::Isfahan),
+    ("88", Province::ChaharmahalAndBakhtiari),
+    ("89", Province::Yazd),
+    ("91", Province::RazaviKhorasan),
+    ("93", Province::RazaviKhorasan),
+    ("94", Province::NorthKhorasan),
+    ("95", Province::RazaviKhorasan),
+    ("96", Province::RazaviKhorasan),
+    ("97", Province::SouthKhorasan),
+    ("98", Province::SistanAndBaluchestan),
+    ("99", Province::SistanAndBaluchestan),
+];
+
+/// Iranian 10-digit postal code (کد پستی).
+///
+/// `Display` renders the dashed `xxxxx-xxxxx` form; [`PostalCode::as_str`] gives the bare digits.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct PostalCode(String);
+
+impl TryFrom<&str> for PostalCode {
+    type Error = PostalCodeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let digits: [u32; 10] = digits(value.chars().filter(|c| !is_separator(*c))).map_err(|err| match err {
+            DigitsError::Length(len) => PostalCodeError::InvalidLength(len),
+            DigitsError::NonDigit => PostalCodeError::NonDigitCharacter,
+        })?;
+
+        // 0 and 2 are never used in the first five digits (the 5th also never holds a 5),
+        // and 2 is never used in the last five.
+        for (position, digit) in digits.iter().enumerate() {
+            let forbidden = match position {
+                0..=3 => *digit == 0 || *digit == 2,
+                4 => *digit == 0 || *digit == 2 || *digit == 5,
+                _ => *digit == 2,
+            };
+            if forbidden {
+                return Err(PostalCodeError::InvalidDigit { position, digit: *digit as u8 });
+            }
+        }
+
+        if digits.iter().all(|d| *d == digits[0]) {
+            return Err(PostalCodeError::RepeatedDigits);
+        }
+        Ok(PostalCode(digits_to_string(&digits)))
+    }
+}
+
+impl TryFrom<String> for PostalCode {
+    type Error = PostalCodeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        PostalCode::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for PostalCode {
+    type Error = PostalCodeError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        PostalCode::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<u64> for PostalCode {
+    type Error = PostalCodeError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        PostalCode::try_from(value.to_string().as_str())
+    }
+}
+
+impl FromStr for PostalCode {
+    type Err = PostalCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PostalCode::try_from(s)
+    }
+}
+
+impl PostalCode {
+    /// Generates a random valid postal code starting with a prefix from the bundled
+    /// province table, so that [`PostalCode::province`] is always known.
+    pub fn random(rng: &mut Rng) -> Self {
+        loop {
+            let (prefix, _) = rng.choose(&PROVINCE_PREFIXES);
+            if let Ok(code) = PostalCode::try_from(rng.digits_after(prefix, 10)) {
+                return code;
+            }
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The canonical ten digits written with Persian digits.
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    /// The province the code is allocated to, according to the bundled prefix table.
+    pub fn province(&self) -> Option<Province> {
+        PROVINCE_PREFIXES.iter()
+            .filter(|(prefix, _)| self.0.starts_with(prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, province)| *province)
+    }
+}
+
+impl AsRef<str> for PostalCode {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for PostalCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}-{}", &self.0[..5], &self.0[5..])
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum PostalCodeError {
+    InvalidLength(usize),
+    NonDigitCharacter,
+    InvalidDigit { position: usize, digit: u8 },
+    RepeatedDigits,
+}
+
+impl Error for PostalCodeError {}
+
+impl Display for PostalCodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PostalCodeError::InvalidLength(len) => {
+                write!(f, "invalid postal code: expected 10 digits, got {}", len)
+            }
+            PostalCodeError::NonDigitCharacter => write!(f, "invalid postal code: contains non-digit characters"),
+            PostalCodeError::InvalidDigit { position, digit } => {
+                write!(f, "invalid postal code: digit {} is not allowed at position {}", digit, position + 1)
+            }
+            PostalCodeError::RepeatedDigits => write!(f, "invalid postal code: all digits are identical"),
+        }
+    }
+}
+
+impl LocalizedError for PostalCodeError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "کد پستی نامعتبر است: ")?;
+        match self {
+            PostalCodeError::InvalidLength(len) => write!(f, "باید 10 رقم باشد، {} رقم وارد شده", len),
+            PostalCodeError::NonDigitCharacter => write!(f, "فقط باید شامل رقم باشد"),
+            PostalCodeError::InvalidDigit { position, digit } => {
+                write!(f, "رقم {} در جایگاه {} مجاز نیست", digit, position + 1)
+            }
+            PostalCodeError::RepeatedDigits => write!(f, "همه ارقام یکسان هستند"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::fake::Rng;
+    use crate::postal_code::{PostalCode, PostalCodeError};
+    use crate::province::Province;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_random_postal_codes_are_valid() {
+        let mut rng = Rng::new(1);
+        for _ in 0..100 {
+            let code = PostalCode::random(&mut rng);
+            assert_eq!(PostalCode::try_from(code.as_str()), Ok(code.clone()));
+            assert!(code.province().is_some());
+        }
+    }
+
+    #[test]
+    fn test_validate_postal_code() {
+        assert!(PostalCode::try_from("1619735744").is_ok());
+        assert!(PostalCode::try_from("16197-35744").is_ok());
+        assert!(PostalCode::try_from("۱۶۱۹۷۳۵۷۴۴").is_ok());
+        assert!("1619735744".parse::<PostalCode>().is_ok());
+    }
+
+    #[test]
+    fn test_display_form() {
+        let code = PostalCode::try_from("۱۶۱۹۷ ۳۵۷۴۴").unwrap();
+        assert_eq!(code.as_str(), "1619735744");
+        assert_eq!(code.to_string(), "16197-35744");
+    }
+
+    #[test]
+    fn test_province() {
+        assert_eq!(PostalCode::try_from("1619735744").unwrap().province(), Some(Province::Tehran));
+        assert_eq!(PostalCode::try_from("8143315741").unwrap().province(), Some(Province::Isfahan));
+        assert_eq!(PostalCode::try_from("7591441111").unwrap().province(), Some(Province::KohgiluyehAndBoyerAhmad));
+        assert_eq!(PostalCode::try_from("7514777777").unwrap().province(), Some(Province::Bushehr));
+        assert_eq!(PostalCode::try_from("7574777777").unwrap().province(), None);
+    }
+
+    #[test]
+    fn test_invalid_postal_code() {
+        assert_eq!(PostalCode::try_from("161973574"), Err(PostalCodeError::InvalidLength(9)));
+        assert_eq!(PostalCode::try_from("161973574a"), Err(PostalCodeError::NonDigitCharacter));
+        assert_eq!(PostalCode::try_from("1019735744"), Err(PostalCodeError::InvalidDigit { position: 1, digit: 0 }));
+        assert_eq!(PostalCode::try_from("1619535744"), Err(PostalCodeError::InvalidDigit { position: 4, digit: 5 }));
+        assert_eq!(PostalCode::try_from("1619735724"), Err(PostalCodeError::InvalidDigit { position: 8, digit: 2 }));
+        assert_eq!(PostalCode::try_from("1111111111"), Err(PostalCodeError::RepeatedDigits));
+    }
+}
@@ -0,0 +1,307 @@
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::error::Error;
+#[cfg(feature = "alloc")]
+use core::fmt::{Display, Formatter};
+
+use crate::province::Province;
+
+/// Place of issuance encoded in the first three digits of a national id, or in the
+/// region code of a license plate.
+///
+/// Locations from the bundled table are `Location<'static>`; those from a loaded
+/// [`PrefixTable`] borrow the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Location<'a> {
+    pub province: Province,
+    pub city: &'a str,
+}
+
+/// Bundled snapshot of national id prefix allocations: (prefix, province, city).
+///
+/// Every province is covered by the prefixes of its main issuing offices; prefixes of
+/// smaller county offices are not listed and resolve to `None`. Load a [`PrefixTable`]
+/// and [`extend`](PrefixTable::extend) the bundled copy for a complete or newer list.
+const PREFIXES: [(&str, Province, &str); 80] = [
+    ("001", Province::Tehran, "Tehran"),
+    ("002", Province::Tehran, "Tehran"),
+    ("003", Province::Tehran, "Tehran"),
+    ("004", Province::Tehran, "Tehran"),
+    ("005", Province::Tehran, "Tehran"),
+    ("006", Province::Tehran, "Tehran"),
+    ("007", Province::Tehran, "Tehran"),
+    ("008", Province::Tehran, "Tehran"),
+    ("015", Province::Tehran, "Eslamshahr"),
+    ("031", Province::Alborz, "Karaj"),
+    ("032", Province::Alborz, "Karaj"),
+    ("037", Province::Qom, "Qom"),
+    ("038", Province::Qom, "Qom"),
+    ("043", Province::Tehran, "Shahriar"),
+    ("048", Province::Tehran, "Varamin"),
+    ("051", Province::Markazi, "Arak"),
+    ("052", Province::Markazi, "Arak"),
+    ("055", Province::Markazi, "Saveh"),
+    ("061", Province::Qazvin, "Qazvin"),
+    ("062", Province::Qazvin, "Qazvin"),
+    ("075", Province::Semnan, "Semnan"),
+    ("078", Province::Semnan, "Shahroud"),
+    ("081", Province::Mazandaran, "Sari"),
+    ("082", Province::Mazandaran, "Sari"),
+    ("084", Province::Golestan, "Gorgan"),
+    ("085", Province::Golestan, "Gorgan"),
+    ("092", Province::RazaviKhorasan, "Mashhad"),
+    ("093", Province::RazaviKhorasan, "Mashhad"),
+    ("094", Province::RazaviKhorasan, "Mashhad"),
+    ("096", Province::NorthKhorasan, "Bojnurd"),
+    ("106", Province::RazaviKhorasan, "Neyshabur"),
+    ("107", Province::RazaviKhorasan, "Neyshabur"),
+    ("112", Province::SouthKhorasan, "Birjand"),
+    ("127", Province::Isfahan, "Isfahan"),
+    ("128", Province::Isfahan, "Isfahan"),
+    ("129", Province::Isfahan, "Isfahan"),
+    ("130", Province::Isfahan, "Kashan"),
+    ("136", Province::EastAzerbaijan, "Tabriz"),
+    ("137", Province::EastAzerbaijan, "Tabriz"),
+    ("138", Province::EastAzerbaijan, "Tabriz"),
+    ("149", Province::EastAzerbaijan, "Ahar"),
+    ("150", Province::EastAzerbaijan, "Ahar"),
+    ("167", Province::EastAzerbaijan, "Maragheh"),
+    ("181", Province::Khuzestan, "Ahvaz"),
+    ("182", Province::Khuzestan, "Ahvaz"),
+    ("228", Province::Fars, "Shiraz"),
+    ("229", Province::Fars, "Shiraz"),
+    ("230", Province::Fars, "Shiraz"),
+    ("246", Province::Bushehr, "Bushehr"),
+    ("247", Province::Bushehr, "Bushehr"),
+    ("263", Province::Gilan, "Rasht"),
+    ("264", Province::Gilan, "Rasht"),
+    ("274", Province::WestAzerbaijan, "Urmia"),
+    ("275", Province::WestAzerbaijan, "Urmia"),
+    ("279", Province::WestAzerbaijan, "Khoy"),
+    ("280", Province::WestAzerbaijan, "Khoy"),
+    ("305", Province::Ilam, "Ilam"),
+    ("313", Province::Kerman, "Kerman"),
+    ("314", Province::Kerman, "Kerman"),
+    ("324", Province::Kermanshah, "Kermanshah"),
+    ("325", Province::Kermanshah, "Kermanshah"),
+    ("335", Province::Hormozgan, "Bandar Abbas"),
+    ("336", Province::Hormozgan, "Bandar Abbas"),
+    ("342", Province::Kurdistan, "Sanandaj"),
+    ("343", Province::Kurdistan, "Sanandaj"),
+    ("361", Province::SistanAndBaluchestan, "Zahedan"),
+    ("362", Province::SistanAndBaluchestan, "Zahedan"),
+    ("386", Province::Hamadan, "Hamadan"),
+    ("387", Province::Hamadan, "Hamadan"),
+    ("411", Province::Ardabil, "Ardabil"),
+    ("412", Province::Ardabil, "Ardabil"),
+    ("420", Province::KohgiluyehAndBoyerAhmad, "Yasuj"),
+    ("423", Province::Lorestan, "Khorramabad"),
+    ("424", Province::Lorestan, "Khorramabad"),
+    ("427", Province::Zanjan, "Zanjan"),
+    ("428", Province::Zanjan, "Zanjan"),
+    ("445", Province::Yazd, "Yazd"),
+    ("446", Province::Yazd, "Yazd"),
+    ("450", Province::ChaharmahalAndBakhtiari, "Shahrekord"),
+    ("451", Province::ChaharmahalAndBakhtiari, "Shahrekord"),
+];
+
+pub(crate) fn lookup(prefix: &str) -> Option<Location<'static>> {
+    PREFIXES.iter()
+        .find(|(p, _, _)| *p == prefix)
+        .map(|(_, province, city)| Location { province: *province, city })
+}
+
+pub(crate) fn prefixes_of(province: Province) -> impl Iterator<Item = &'static str> {
+    PREFIXES.iter().filter(move |(_, p, _)| *p == province).map(|(prefix, _, _)| *prefix)
+}
+
+/// A prefix allocation table loaded at runtime, for when the bundled snapshot is out of date.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixTable {
+    entries: Vec<([u8; 3], Province, String)>,
+}
+
+#[cfg(feature = "alloc")]
+impl PrefixTable {
+    /// A copy of the bundled snapshot, to extend or override.
+    pub fn bundled() -> Self {
+        let entries = PREFIXES.iter()
+            .map(|(prefix, province, city)| (parse_prefix(prefix).unwrap(), *province, city.to_string()))
+            .collect();
+        PrefixTable { entries }
+    }
+
+    /// Parses `prefix,province,city` lines, e.g. `031,Alborz,Karaj`.
+    ///
+    /// Provinces are parsed like [`Province::from_str`](core::str::FromStr), so ISO codes and
+    /// persian names work too. Blank lines, lines starting with `#` and a leading
+    /// `prefix,province,city` header are skipped. Later lines override earlier ones for
+    /// the same prefix.
+    pub fn from_csv(text: &str) -> Result<Self, PrefixTableError> {
+        let mut table = PrefixTable { entries: Vec::new() };
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || (i == 0 && line.eq_ignore_ascii_case("prefix,province,city")) {
+                continue;
+            }
+
+            let number = i + 1;
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let (prefix, province, city) = match fields[..] {
+                [prefix, province, city] if !city.is_empty() => (prefix, province, city),
+                _ => return Err(PrefixTableError::InvalidLine(number)),
+            };
+            let prefix = parse_prefix(prefix).ok_or(PrefixTableError::InvalidPrefix(number))?;
+            let province = province.parse().map_err(|_| PrefixTableError::UnknownProvince(number))?;
+            table.insert(prefix, province, city);
+        }
+        Ok(table)
+    }
+
+    /// Reads a table in the format of [`PrefixTable::from_csv`].
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: std::io::Read>(mut reader: R) -> Result<Self, PrefixTableError> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text).map_err(|err| PrefixTableError::Io(err.kind()))?;
+        PrefixTable::from_csv(&text)
+    }
+
+    fn insert(&mut self, prefix: [u8; 3], province: Province, city: &str) {
+        match self.entries.iter_mut().find(|(p, _, _)| *p == prefix) {
+            Some(entry) => *entry = (prefix, province, city.to_string()),
+            None => self.entries.push((prefix, province, city.to_string())),
+        }
+    }
+
+    /// Adds the entries of `other`, replacing those with the same prefix; e.g. to apply
+    /// updated allocations on top of [`PrefixTable::bundled`].
+    pub fn extend(&mut self, other: &PrefixTable) {
+        for (prefix, province, city) in &other.entries {
+            self.insert(*prefix, *province, city);
+        }
+    }
+
+    /// The location allocated to a three-digit prefix.
+    pub fn lookup(&self, prefix: &str) -> Option<Location<'_>> {
+        self.entries.iter()
+            .find(|(p, _, _)| p[..] == *prefix.as_bytes())
+            .map(|(_, province, city)| Location { province: *province, city })
+    }
+
+    /// Prefixes allocated to offices in `province`, in table order.
+    pub fn prefixes_of(&self, province: Province) -> impl Iterator<Item = &str> {
+        self.entries.iter()
+            .filter(move |(_, p, _)| *p == province)
+            .map(|(prefix, _, _)| core::str::from_utf8(prefix).unwrap())
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn parse_prefix(prefix: &str) -> Option<[u8; 3]> {
+    match prefix.as_bytes() {
+        digits @ [_, _, _] if digits.iter().all(u8::is_ascii_digit) => Some([digits[0], digits[1], digits[2]]),
+        _ => None,
+    }
+}
+
+/// Why a prefix table could not be loaded; line numbers start at 1.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrefixTableError {
+    InvalidLine(usize),
+    InvalidPrefix(usize),
+    UnknownProvince(usize),
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+#[cfg(feature = "alloc")]
+impl Error for PrefixTableError {}
+
+#[cfg(feature = "alloc")]
+impl Display for PrefixTableError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PrefixTableError::InvalidLine(line) => {
+                write!(f, "invalid prefix table: line {} is not prefix,province,city", line)
+            }
+            PrefixTableError::InvalidPrefix(line) => {
+                write!(f, "invalid prefix table: line {} does not start with a three-digit prefix", line)
+            }
+            PrefixTableError::UnknownProvince(line) => write!(f, "invalid prefix table: unknown province on line {}", line),
+            #[cfg(feature = "std")]
+            PrefixTableError::Io(kind) => write!(f, "could not read prefix table: {}", kind),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::national_id::NationalId;
+    use crate::prefix_table::{Location, PrefixTable, PrefixTableError};
+    use crate::province::Province;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_load_table() {
+        let csv = "prefix,province,city\n# updated allocations\n004,Tehran,Shemiranat\n\n081,IR-18,برازجان\n";
+        let table = PrefixTable::from_csv(csv).unwrap();
+        assert_eq!(table.len(), 2);
+
+        let id = NationalId::try_from("0814659438").unwrap();
+        assert_eq!(id.issuance_location(), Some(Location { province: Province::Mazandaran, city: "Sari" }));
+        assert_eq!(id.issuance_location_in(&table), Some(Location { province: Province::Bushehr, city: "برازجان" }));
+        assert_eq!(table.prefixes_of(Province::Tehran).collect::<Vec<_>>(), vec!["004"]);
+        assert_eq!(PrefixTable::from_reader(csv.as_bytes()), Ok(table));
+    }
+
+    #[test]
+    fn test_bundled_copy_and_overrides() {
+        let table = PrefixTable::bundled();
+        assert_eq!(table.len(), 80);
+        assert_eq!(table.lookup("031"), crate::prefix_table::lookup("031"));
+
+        let table = PrefixTable::from_csv("031,Alborz,Karaj\n031,Alborz,Fardis").unwrap();
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.lookup("031").unwrap().city, "Fardis");
+
+        let mut bundled = PrefixTable::bundled();
+        bundled.extend(&PrefixTable::from_csv("031,Alborz,Fardis\n081,Bushehr,Borazjan").unwrap());
+        assert_eq!(bundled.len(), 80);
+        assert_eq!(bundled.lookup("031").unwrap().city, "Fardis");
+        assert_eq!(bundled.lookup("081"), Some(Location { province: Province::Bushehr, city: "Borazjan" }));
+        assert_eq!(bundled.lookup("092"), crate::prefix_table::lookup("092"));
+    }
+
+    #[test]
+    fn test_bundled_table_covers_every_province() {
+        for province in Province::all() {
+            assert!(crate::prefix_table::prefixes_of(province).next().is_some(), "no prefix for {}", province);
+            assert!(PrefixTable::bundled().prefixes_of(province).next().is_some());
+        }
+        assert_eq!(crate::prefix_table::lookup("111"), None);
+    }
+
+    #[test]
+    fn test_invalid_table() {
+        assert_eq!(PrefixTable::from_csv("001,Tehran"), Err(PrefixTableError::InvalidLine(1)));
+        assert_eq!(PrefixTable::from_csv("\n01,Tehran,Tehran"), Err(PrefixTableError::InvalidPrefix(2)));
+        assert_eq!(PrefixTable::from_csv("001,Atlantis,Tehran"), Err(PrefixTableError::UnknownProvince(1)));
+        assert_eq!(
+            PrefixTableError::UnknownProvince(3).to_string(),
+            "invalid prefix table: unknown province on line 3"
+        );
+    }
+}
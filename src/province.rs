@@ -0,0 +1,181 @@
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// The 31 provinces of Iran, in ISO 3166-2:IR order.
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum Province {
+    Markazi,
+    Gilan,
+    Mazandaran,
+    EastAzerbaijan,
+    WestAzerbaijan,
+    Kermanshah,
+    Khuzestan,
+    Fars,
+    Kerman,
+    RazaviKhorasan,
+    Isfahan,
+    SistanAndBaluchestan,
+    Kurdistan,
+    Hamadan,
+    ChaharmahalAndBakhtiari,
+    Lorestan,
+    Ilam,
+    KohgiluyehAndBoyerAhmad,
+    Bushehr,
+    Zanjan,
+    Semnan,
+    Yazd,
+    Hormozgan,
+    Tehran,
+    Ardabil,
+    Qom,
+    Qazvin,
+    Golestan,
+    NorthKhorasan,
+    SouthKhorasan,
+    Alborz,
+}
+
+/// (province, ISO 3166-2 code, english name, persian name)
+const PROVINCES: [(Province, &str, &str, &str); 31] = [
+    (Province::Markazi, "IR-00", "Markazi", "مرکزی"),
+    (Province::Gilan, "IR-01", "Gilan", "گیلان"),
+    (Province::Mazandaran, "IR-02", "Mazandaran", "مازندران"),
+    (Province::EastAzerbaijan, "IR-03", "East Azerbaijan", "آذربایجان شرقی"),
+    (Province::WestAzerbaijan, "IR-04", "West Azerbaijan", "آذربایجان غربی"),
+    (Province::Kermanshah, "IR-05", "Kermanshah", "کرمانشاه"),
+    (Province::Khuzestan, "IR-06", "Khuzestan", "خوزستان"),
+    (Province::Fars, "IR-07", "Fars", "فارس"),
+    (Province::Kerman, "IR-08", "Kerman", "کرمان"),
+    (Province::RazaviKhorasan, "IR-09", "Razavi Khorasan", "خراسان رضوی"),
+    (Province::Isfahan, "IR-10", "Isfahan", "اصفهان"),
+    (Province::SistanAndBaluchestan, "IR-11", "Sistan and Baluchestan", "سیستان و بلوچستان"),
+    (Province::Kurdistan, "IR-12", "Kurdistan", "کردستان"),
+    (Province::Hamadan, "IR-13", "Hamadan", "همدان"),
+    (Province::ChaharmahalAndBakhtiari, "IR-14", "Chaharmahal and Bakhtiari", "چهارمحال و بختیاری"),
+    (Province::Lorestan, "IR-15", "Lorestan", "لرستان"),
+    (Province::Ilam, "IR-16", "Ilam", "ایلام"),
+    (Province::KohgiluyehAndBoyerAhmad, "IR-17", "Kohgiluyeh and Boyer-Ahmad", "کهگیلویه و بویراحمد"),
+    (Province::Bushehr, "IR-18", "Bushehr", "بوشهر"),
+    (Province::Zanjan, "IR-19", "Zanjan", "زنجان"),
+    (Province::Semnan, "IR-20", "Semnan", "سمنان"),
+    (Province::Yazd, "IR-21", "Yazd", "یزد"),
+    (Province::Hormozgan, "IR-22", "Hormozgan", "هرمزگان"),
+    (Province::Tehran, "IR-23", "Tehran", "تهران"),
+    (Province::Ardabil, "IR-24", "Ardabil", "اردبیل"),
+    (Province::Qom, "IR-25", "Qom", "قم"),
+    (Province::Qazvin, "IR-26", "Qazvin", "قزوین"),
+    (Province::Golestan, "IR-27", "Golestan", "گلستان"),
+    (Province::NorthKhorasan, "IR-28", "North Khorasan", "خراسان شمالی"),
+    (Province::SouthKhorasan, "IR-29", "South Khorasan", "خراسان جنوبی"),
+    (Province::Alborz, "IR-30", "Alborz", "البرز"),
+];
+
+/// Population at the 1395 (2016) national census, in province declaration order.
+const POPULATION: [u32; 31] = [
+    1_429_475, 2_530_696, 3_283_582, 3_909_652, 3_265_219, 1_952_434, 4_710_509, 4_851_274,
+    3_164_718, 6_434_501, 5_120_850, 2_775_014, 1_603_011, 1_738_234, 947_763, 1_760_649,
+    580_158, 713_052, 1_163_400, 1_057_461, 702_360, 1_138_533, 1_776_415, 13_267_637,
+    1_270_420, 1_292_283, 1_273_761, 1_868_819, 863_092, 768_898, 2_712_400,
+];
+
+impl Province {
+    pub fn all() -> impl Iterator<Item = Province> {
+        PROVINCES.iter().map(|(province, _, _, _)| *province)
+    }
+
+    /// ISO 3166-2:IR subdivision code, e.g. `IR-23` for Tehran.
+    pub fn iso_code(&self) -> &'static str {
+        self.entry().1
+    }
+
+    pub fn english_name(&self) -> &'static str {
+        self.entry().2
+    }
+
+    pub fn persian_name(&self) -> &'static str {
+        self.entry().3
+    }
+
+    /// Population at the 1395 (2016) national census.
+    pub fn population(&self) -> u32 {
+        POPULATION[*self as usize]
+    }
+
+    fn entry(&self) -> &'static (Province, &'static str, &'static str, &'static str) {
+        &PROVINCES[*self as usize]
+    }
+}
+
+/// Writes the english name, or the persian name with the alternate flag (`{:#}`).
+impl Display for Province {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.persian_name())
+        } else {
+            write!(f, "{}", self.english_name())
+        }
+    }
+}
+
+/// Parses an ISO 3166-2:IR code, an english name (case-insensitive) or a persian name.
+impl FromStr for Province {
+    type Err = UnknownProvince;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        PROVINCES.iter()
+            .find(|(_, iso, en, fa)| iso.eq_ignore_ascii_case(s) || en.eq_ignore_ascii_case(s) || *fa == s)
+            .map(|(province, _, _, _)| *province)
+            .ok_or(UnknownProvince)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub struct UnknownProvince;
+
+impl Error for UnknownProvince {}
+
+impl Display for UnknownProvince {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown iranian province")
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::province::{Province, UnknownProvince, PROVINCES};
+
+    #[test]
+    fn test_names_and_codes() {
+        assert_eq!(Province::Tehran.iso_code(), "IR-23");
+        assert_eq!(Province::Tehran.english_name(), "Tehran");
+        assert_eq!(Province::Tehran.persian_name(), "تهران");
+        assert_eq!(format!("{}", Province::EastAzerbaijan), "East Azerbaijan");
+        assert_eq!(format!("{:#}", Province::EastAzerbaijan), "آذربایجان شرقی");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("IR-10".parse::<Province>(), Ok(Province::Isfahan));
+        assert_eq!("razavi khorasan".parse::<Province>(), Ok(Province::RazaviKhorasan));
+        assert_eq!("البرز".parse::<Province>(), Ok(Province::Alborz));
+        assert_eq!("Atlantis".parse::<Province>(), Err(UnknownProvince));
+    }
+
+    #[test]
+    fn test_table_is_in_declaration_order() {
+        for (i, (province, _, _, _)) in PROVINCES.iter().enumerate() {
+            assert_eq!(*province as usize, i);
+        }
+        assert_eq!(Province::all().count(), 31);
+    }
+
+    #[test]
+    fn test_population() {
+        assert_eq!(Province::Tehran.population(), 13_267_637);
+        assert_eq!(Province::all().map(|p| p.population() as u64).sum::<u64>(), 79_926_270);
+    }
+}
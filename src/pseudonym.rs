@@ -0,0 +1,86 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display, Formatter};
+
+use crate::national_id::NationalId;
+use crate::sha256::HmacSha256;
+
+/// Stable token derived from a national id with HMAC-SHA256 under a secret key.
+///
+/// The same id and key always give the same token, so pseudonymized datasets can still
+/// be joined, while the id cannot be recovered from the token without the key.
+#[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct Pseudonym([u8; 32]);
+
+impl Pseudonym {
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+/// Lowercase hex, 64 characters.
+impl Display for Pseudonym {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.0.iter().try_for_each(|b| write!(f, "{:02x}", b))
+    }
+}
+
+impl Debug for Pseudonym {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Pseudonym({})", self)
+    }
+}
+
+/// Pseudonymizes many ids under one key, preparing the key only once.
+#[derive(Clone)]
+pub struct Pseudonymizer(HmacSha256);
+
+impl Pseudonymizer {
+    pub fn new(key: &[u8]) -> Self {
+        Pseudonymizer(HmacSha256::new(key))
+    }
+
+    pub fn pseudonymize(&self, id: &NationalId) -> Pseudonym {
+        Pseudonym(self.0.mac(id.as_str().as_bytes()))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn pseudonymize_all<'a, I: IntoIterator<Item = &'a NationalId>>(&self, ids: I) -> Vec<Pseudonym> {
+        ids.into_iter().map(|id| self.pseudonymize(id)).collect()
+    }
+}
+
+/// Hides the key state.
+impl Debug for Pseudonymizer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Pseudonymizer")
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::national_id::NationalId;
+    use crate::pseudonym::Pseudonymizer;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_pseudonym_is_hmac_of_canonical_form() {
+        let id = NationalId::try_from("40010007").unwrap();
+        let token = id.pseudonymize(b"Jefe");
+        assert_eq!(token.to_string(), "6614377929b4c84ec4bdd5611def1f00d48214fb3f388f3a5170084bb3ef94b5");
+        assert_eq!(token, NationalId::try_from("0040010007").unwrap().pseudonymize(b"Jefe"));
+        assert_ne!(token, id.pseudonymize(b"other key"));
+    }
+
+    #[test]
+    fn test_batch_matches_single() {
+        let ids = crate::fake::national_ids(1, 20);
+        let pseudonymizer = Pseudonymizer::new(b"secret");
+        let tokens = pseudonymizer.pseudonymize_all(&ids);
+        assert_eq!(tokens.len(), 20);
+        for (id, token) in ids.iter().zip(&tokens) {
+            assert_eq!(*token, id.pseudonymize(b"secret"));
+        }
+        assert_eq!(format!("{:?}", pseudonymizer), "Pseudonymizer");
+    }
+}
@@ -0,0 +1,162 @@
+use alloc::string::{String, ToString};
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::locale::LocalizedError;
+use crate::normalize::{is_separator, normalize_digit, to_persian_digits};
+
+const MAX_DIGITS: usize = 10;
+
+/// Company registration number (شماره ثبت), the serial assigned by the registration office.
+///
+/// Registration numbers have no check digit and are only unique within the office that
+/// issued them; pair them with a [`LegalNationalId`](crate::LegalNationalId) to identify a
+/// company. The canonical form has no leading zeros.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct RegistrationNumber(String);
+
+impl TryFrom<&str> for RegistrationNumber {
+    type Error = RegistrationNumberError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value: String = value.chars()
+            .filter(|c| !is_separator(*c))
+            .map(normalize_digit)
+            .collect();
+        let len = value.chars().count();
+        if len == 0 || len > MAX_DIGITS {
+            return Err(RegistrationNumberError::InvalidLength(len));
+        }
+
+        if !value.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(RegistrationNumberError::NonDigitCharacter);
+        }
+
+        match value.trim_start_matches('0') {
+            "" => Err(RegistrationNumberError::Zero),
+            digits => Ok(RegistrationNumber(digits.to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for RegistrationNumber {
+    type Error = RegistrationNumberError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        RegistrationNumber::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for RegistrationNumber {
+    type Error = RegistrationNumberError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        RegistrationNumber::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<u64> for RegistrationNumber {
+    type Error = RegistrationNumberError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        RegistrationNumber::try_from(value.to_string().as_str())
+    }
+}
+
+impl FromStr for RegistrationNumber {
+    type Err = RegistrationNumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RegistrationNumber::try_from(s)
+    }
+}
+
+impl RegistrationNumber {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The number written with Persian digits.
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    pub fn to_u64(&self) -> u64 {
+        self.0.parse().unwrap()
+    }
+}
+
+impl AsRef<str> for RegistrationNumber {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for RegistrationNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum RegistrationNumberError {
+    InvalidLength(usize),
+    NonDigitCharacter,
+    Zero,
+}
+
+impl Error for RegistrationNumberError {}
+
+impl Display for RegistrationNumberError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RegistrationNumberError::InvalidLength(len) => {
+                write!(f, "invalid registration number: expected 1 to {} digits, got {}", MAX_DIGITS, len)
+            }
+            RegistrationNumberError::NonDigitCharacter => {
+                write!(f, "invalid registration number: contains non-digit characters")
+            }
+            RegistrationNumberError::Zero => write!(f, "invalid registration number: must not be zero"),
+        }
+    }
+}
+
+impl LocalizedError for RegistrationNumberError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "شماره ثبت نامعتبر است: ")?;
+        match self {
+            RegistrationNumberError::InvalidLength(len) => {
+                write!(f, "باید 1 تا {} رقم باشد، {} رقم وارد شده", MAX_DIGITS, len)
+            }
+            RegistrationNumberError::NonDigitCharacter => write!(f, "فقط باید شامل رقم باشد"),
+            RegistrationNumberError::Zero => write!(f, "نباید صفر باشد"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::registration_number::{RegistrationNumber, RegistrationNumberError};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_validate_registration_number() {
+        assert_eq!(RegistrationNumber::try_from(" ۱۲۳۴۵۶ ").unwrap().as_str(), "123456");
+        assert_eq!(RegistrationNumber::try_from("00042").unwrap().to_u64(), 42);
+        assert_eq!(RegistrationNumber::try_from(1234u64).unwrap().to_string(), "1234");
+    }
+
+    #[test]
+    fn test_invalid_registration_number() {
+        assert_eq!(RegistrationNumber::try_from(""), Err(RegistrationNumberError::InvalidLength(0)));
+        assert_eq!(RegistrationNumber::try_from("12345678901"), Err(RegistrationNumberError::InvalidLength(11)));
+        assert_eq!(RegistrationNumber::try_from("12a"), Err(RegistrationNumberError::NonDigitCharacter));
+        assert_eq!(RegistrationNumber::try_from("000"), Err(RegistrationNumberError::Zero));
+    }
+}
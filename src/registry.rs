@@ -0,0 +1,163 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use core::error::Error;
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+
+use crate::bank_card::BankCard;
+use crate::birth_certificate::BirthCertificateNumber;
+use crate::driving_license::DrivingLicense;
+use crate::economic_code::EconomicCode;
+use crate::foreign_national_id::ForeignNationalId;
+use crate::identifier::Identifier;
+use crate::isin::Isin;
+use crate::landline_number::LandlineNumber;
+use crate::legal_national_id::LegalNationalId;
+use crate::license_plate::LicensePlate;
+use crate::mobile_number::MobileNumber;
+use crate::national_card_serial::NationalCardSerial;
+use crate::national_id::NationalId;
+use crate::passport_number::PassportNumber;
+use crate::person_id::PersonId;
+use crate::postal_code::PostalCode;
+use crate::registration_number::RegistrationNumber;
+use crate::sayad_id::SayadId;
+use crate::sheba::Sheba;
+use crate::social_security::{InsuranceNumber, WorkshopCode};
+
+/// A validator picked at runtime, e.g. from a field type in a form definition.
+pub trait Validator: Send + Sync {
+    /// Returns the normalized form of a valid `value`.
+    fn validate(&self, value: &str) -> Result<String, Box<dyn Error + Send + Sync>>;
+
+    fn is_valid(&self, value: &str) -> bool {
+        self.validate(value).is_ok()
+    }
+}
+
+/// Validates values as `T`.
+struct IdentifierValidator<T>(PhantomData<fn() -> T>);
+
+impl<T> Validator for IdentifierValidator<T>
+where
+    T: Identifier,
+    T::Error: Send + Sync,
+{
+    fn validate(&self, value: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+        match T::parse(value) {
+            Ok(id) => Ok(id.normalized()),
+            Err(err) => Err(Box::new(err)),
+        }
+    }
+}
+
+/// Validators keyed by name.
+///
+/// The default registry holds every identifier type under its
+/// [`IdentifierKind::name`](crate::IdentifierKind::name), plus `person_id`.
+pub struct ValidatorRegistry {
+    validators: BTreeMap<String, Box<dyn Validator>>,
+}
+
+impl ValidatorRegistry {
+    /// An empty registry.
+    pub fn new() -> Self {
+        ValidatorRegistry { validators: BTreeMap::new() }
+    }
+
+    /// Adds `validator` under `name`, replacing any validator already registered there.
+    pub fn register(&mut self, name: impl Into<String>, validator: Box<dyn Validator>) {
+        self.validators.insert(name.into(), validator);
+    }
+
+    /// Adds a validator parsing values as `T` under `name`.
+    pub fn register_identifier<T>(&mut self, name: impl Into<String>)
+    where
+        T: Identifier + 'static,
+        T::Error: Send + Sync,
+    {
+        self.register(name, Box::new(IdentifierValidator::<T>(PhantomData)));
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn Validator> {
+        self.validators.get(name).map(|validator| validator.as_ref())
+    }
+
+    /// Registered names, in lexicographic order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.validators.keys().map(String::as_str)
+    }
+}
+
+impl Default for ValidatorRegistry {
+    fn default() -> Self {
+        let mut registry = ValidatorRegistry::new();
+        registry.register_identifier::<NationalId>("national_id");
+        registry.register_identifier::<LegalNationalId>("legal_national_id");
+        registry.register_identifier::<ForeignNationalId>("foreign_national_id");
+        registry.register_identifier::<PersonId>("person_id");
+        registry.register_identifier::<EconomicCode>("economic_code");
+        registry.register_identifier::<Sheba>("sheba");
+        registry.register_identifier::<BankCard>("bank_card");
+        registry.register_identifier::<PostalCode>("postal_code");
+        registry.register_identifier::<MobileNumber>("mobile_number");
+        registry.register_identifier::<LandlineNumber>("landline_number");
+        registry.register_identifier::<Isin>("isin");
+        registry.register_identifier::<LicensePlate>("license_plate");
+        registry.register_identifier::<RegistrationNumber>("registration_number");
+        registry.register_identifier::<SayadId>("sayad_id");
+        registry.register_identifier::<BirthCertificateNumber>("birth_certificate_number");
+        registry.register_identifier::<InsuranceNumber>("insurance_number");
+        registry.register_identifier::<WorkshopCode>("workshop_code");
+        registry.register_identifier::<DrivingLicense>("driving_license");
+        registry.register_identifier::<PassportNumber>("passport_number");
+        registry.register_identifier::<NationalCardSerial>("national_card_serial");
+        registry
+    }
+}
+
+impl Debug for ValidatorRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.names()).finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::identifier::IdentifierKind;
+    use crate::registry::{Validator, ValidatorRegistry};
+    use std::error::Error;
+
+    struct NonEmpty;
+
+    impl Validator for NonEmpty {
+        fn validate(&self, value: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+            if value.is_empty() { Err("empty".into()) } else { Ok(value.to_string()) }
+        }
+    }
+
+    #[test]
+    fn test_default_registry() {
+        let registry = ValidatorRegistry::default();
+        let validator = registry.get("national_id").unwrap();
+        assert_eq!(validator.validate("۴۰۰۱۰۰۰۷").unwrap(), "0040010007");
+        assert_eq!(
+            validator.validate("0040010008").unwrap_err().to_string(),
+            "invalid iranian national id number: check digit should be 7, found 8"
+        );
+        assert!(registry.get("sheba").unwrap().is_valid("IR062960000000100324200001"));
+        assert!(registry.get("passport").is_none());
+        assert_eq!(registry.names().count(), 20);
+        assert!(registry.get(IdentifierKind::MobileNumber.name()).unwrap().is_valid("09123456789"));
+    }
+
+    #[test]
+    fn test_custom_validator() {
+        let mut registry = ValidatorRegistry::new();
+        registry.register("name", Box::new(NonEmpty));
+        assert!(registry.get("name").unwrap().is_valid("Ali"));
+        assert!(!registry.get("name").unwrap().is_valid(""));
+        assert_eq!(format!("{:?}", registry), "{\"name\"}");
+    }
+}
@@ -0,0 +1,159 @@
+use alloc::format;
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::locale::LocalizedError;
+use crate::normalize::{digits, digits_to_string, is_separator, to_persian_digits, DigitsError};
+
+/// The 16-digit Sayad id (شناسه صیادی) printed on cheques registered in the national
+/// cheque system.
+///
+/// The id has no published check digit, so only its shape is validated. `Display`
+/// prints the bare digits; the alternate form (`{:#}`) groups them by four, e.g.
+/// `1234-5678-9012-3456`.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct SayadId(String);
+
+impl TryFrom<&str> for SayadId {
+    type Error = SayadIdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let digits: [u32; 16] = digits(value.chars().filter(|c| !is_separator(*c))).map_err(|err| match err {
+            DigitsError::Length(len) => SayadIdError::InvalidLength(len),
+            DigitsError::NonDigit => SayadIdError::NonDigitCharacter,
+        })?;
+
+        if digits.iter().all(|d| *d == 0) {
+            return Err(SayadIdError::AllZeros);
+        }
+        Ok(SayadId(digits_to_string(&digits)))
+    }
+}
+
+impl TryFrom<String> for SayadId {
+    type Error = SayadIdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        SayadId::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for SayadId {
+    type Error = SayadIdError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        SayadId::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<u64> for SayadId {
+    type Error = SayadIdError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        SayadId::try_from(format!("{:016}", value))
+    }
+}
+
+impl FromStr for SayadId {
+    type Err = SayadIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SayadId::try_from(s)
+    }
+}
+
+impl SayadId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The id written with Persian digits, without grouping.
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    /// Groups of four digits separated by dashes; same as `format!("{:#}", id)`.
+    pub fn formatted(&self) -> String {
+        format!("{:#}", self)
+    }
+}
+
+impl AsRef<str> for SayadId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for SayadId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if !f.alternate() {
+            return write!(f, "{}", self.0);
+        }
+        for (i, group) in self.0.as_bytes().chunks(4).enumerate() {
+            if i > 0 {
+                write!(f, "-")?;
+            }
+            write!(f, "{}", core::str::from_utf8(group).unwrap())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum SayadIdError {
+    InvalidLength(usize),
+    NonDigitCharacter,
+    AllZeros,
+}
+
+impl Error for SayadIdError {}
+
+impl Display for SayadIdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SayadIdError::InvalidLength(len) => write!(f, "invalid sayad id: expected 16 digits, got {}", len),
+            SayadIdError::NonDigitCharacter => write!(f, "invalid sayad id: contains non-digit characters"),
+            SayadIdError::AllZeros => write!(f, "invalid sayad id: all digits are zero"),
+        }
+    }
+}
+
+impl LocalizedError for SayadIdError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "شناسه صیادی نامعتبر است: ")?;
+        match self {
+            SayadIdError::InvalidLength(len) => write!(f, "باید 16 رقم باشد، {} رقم وارد شده", len),
+            SayadIdError::NonDigitCharacter => write!(f, "فقط باید شامل رقم باشد"),
+            SayadIdError::AllZeros => write!(f, "همه ارقام صفر است"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::sayad_id::{SayadId, SayadIdError};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_validate_sayad_id() {
+        let id = SayadId::try_from(" ۱۲۳۴-۵۶۷۸-۹۰۱۲-۳۴۵۶ ").unwrap();
+        assert_eq!(id.as_str(), "1234567890123456");
+        assert_eq!(id.to_string(), "1234567890123456");
+        assert_eq!(id.formatted(), "1234-5678-9012-3456");
+        assert_eq!(SayadId::try_from(4567890123456u64).unwrap().as_str(), "0004567890123456");
+    }
+
+    #[test]
+    fn test_invalid_sayad_id() {
+        assert_eq!(SayadId::try_from("123456789012345"), Err(SayadIdError::InvalidLength(15)));
+        assert_eq!(SayadId::try_from("123456789012345a"), Err(SayadIdError::NonDigitCharacter));
+        assert_eq!(SayadId::try_from("0000 0000 0000 0000"), Err(SayadIdError::AllZeros));
+    }
+}
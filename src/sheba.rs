@@ -0,0 +1,325 @@
+use alloc::format;
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::bank::Bank;
+use crate::bank_account::BankAccount;
+use crate::fake::Rng;
+use crate::locale::LocalizedError;
+use crate::normalize::{normalize_digit, to_persian_digits};
+
+/// Iranian IBAN (شبا): `IR` followed by two check digits and a 22-digit BBAN.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct Sheba(String);
+
+impl TryFrom<&str> for Sheba {
+    type Error = ShebaError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value: String = value.chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| normalize_digit(c).to_ascii_uppercase())
+            .collect();
+        let len = value.chars().count();
+        if len != 26 {
+            return Err(ShebaError::InvalidLength(len));
+        }
+
+        if !value.starts_with("IR") {
+            return Err(ShebaError::InvalidCountryCode);
+        }
+
+        if !value[2..].bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ShebaError::NonDigitCharacter);
+        }
+
+        if mod97(&value) != 1 {
+            return Err(ShebaError::InvalidChecksum);
+        }
+        Ok(Sheba(value))
+    }
+}
+
+/// ISO 13616 remainder of `iban` rearranged with its first four characters moved to the end.
+fn mod97(iban: &str) -> u32 {
+    let (head, bban) = iban.split_at(4);
+    bban.chars().chain(head.chars()).fold(0, |rem, c| {
+        let n = c.to_digit(36).unwrap();
+        if n < 10 { (rem * 10 + n) % 97 } else { (rem * 100 + n) % 97 }
+    })
+}
+
+impl TryFrom<String> for Sheba {
+    type Error = ShebaError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Sheba::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for Sheba {
+    type Error = ShebaError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        Sheba::try_from(value.as_str())
+    }
+}
+
+impl FromStr for Sheba {
+    type Err = ShebaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Sheba::try_from(s)
+    }
+}
+
+impl Sheba {
+    /// Builds a Sheba from a three-digit bank code and an account identifier of up to
+    /// 19 digits, which is zero-padded on the left, computing the MOD-97 check digits.
+    pub fn from_bban(bank_code: &str, account: &str) -> Result<Self, ShebaError> {
+        let bank_code: String = bank_code.chars().map(normalize_digit).collect();
+        let account: String = account.chars().map(normalize_digit).collect();
+        if !bank_code.bytes().chain(account.bytes()).all(|b| b.is_ascii_digit()) {
+            return Err(ShebaError::NonDigitCharacter);
+        }
+        if bank_code.len() != 3 {
+            return Err(ShebaError::InvalidBankCode(bank_code.len()));
+        }
+        if account.len() > 19 {
+            return Err(ShebaError::AccountTooLong(account.len()));
+        }
+
+        let bban = format!("{}{:0>19}", bank_code, account);
+        let check = 98 - mod97(&format!("IR00{}", bban));
+        Ok(Sheba(format!("IR{:02}{}", check, bban)))
+    }
+
+    /// Generates a random valid Sheba of an account at `bank`.
+    pub fn random(bank: Bank, rng: &mut Rng) -> Self {
+        Sheba::from_bban(bank.code(), &rng.digits_after("0", 19)).unwrap()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The Sheba with its digits in Persian, e.g. `IR۰۶۲۹۶۰۰۰۰۰۰۰۱۰۰۳۲۴۲۰۰۰۰۱`; the `IR` prefix is kept.
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    /// Groups of four characters separated by spaces, e.g. `IR06 2960 0000 0010 0324 2000 01`;
+    /// the same as `format!("{:#}", sheba)`.
+    pub fn formatted(&self) -> String {
+        format!("{:#}", self)
+    }
+
+    /// The two digits following `IR`.
+    pub fn check_digits(&self) -> u8 {
+        self.0[2..4].parse().unwrap()
+    }
+
+    /// The 22 digits following the check digits.
+    pub fn bban(&self) -> &str {
+        &self.0[4..]
+    }
+
+    /// The first three digits of the BBAN, identifying the bank.
+    pub fn bank_code(&self) -> &str {
+        &self.0[4..7]
+    }
+
+    /// The last 19 digits of the BBAN, identifying the account within the bank.
+    pub fn account_identifier(&self) -> &str {
+        &self.0[7..]
+    }
+
+    /// The bank identified by [`Sheba::bank_code`], or `None` if the code is not
+    /// allocated to a known bank.
+    pub fn bank(&self) -> Option<Bank> {
+        Bank::from_code(self.bank_code())
+    }
+
+    /// The local account number encoded in [`Sheba::account_identifier`], for the banks
+    /// that place it there verbatim (Melli, Mellat, Tejarat and Ayandeh); `None` for
+    /// other banks, whose account field cannot be mapped back without their own tables.
+    pub fn account_number(&self) -> Option<BankAccount> {
+        self.account_parts().map(|(_, account)| account)
+    }
+
+    /// The account type digit leading [`Sheba::account_identifier`], for the banks
+    /// supported by [`Sheba::account_number`].
+    pub fn account_type(&self) -> Option<u8> {
+        self.account_parts().map(|(account_type, _)| account_type)
+    }
+
+    fn account_parts(&self) -> Option<(u8, BankAccount)> {
+        BankAccount::from_sheba_field(self.bank()?, self.account_identifier())
+    }
+}
+
+impl AsRef<str> for Sheba {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// The compact form; the alternate form (`{:#}`) groups it by four characters.
+impl Display for Sheba {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if !f.alternate() {
+            return write!(f, "{}", self.0);
+        }
+        for (i, group) in self.0.as_bytes().chunks(4).enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", core::str::from_utf8(group).unwrap())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum ShebaError {
+    InvalidLength(usize),
+    InvalidCountryCode,
+    NonDigitCharacter,
+    InvalidChecksum,
+    /// From [`Sheba::from_bban`]: the bank code does not have exactly three digits.
+    InvalidBankCode(usize),
+    /// From [`Sheba::from_bban`]: the account has more than 19 digits.
+    AccountTooLong(usize),
+}
+
+impl Error for ShebaError {}
+
+impl Display for ShebaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ShebaError::InvalidLength(len) => {
+                write!(f, "invalid sheba number: expected 26 characters, got {}", len)
+            }
+            ShebaError::InvalidCountryCode => write!(f, "invalid sheba number: must start with IR"),
+            ShebaError::NonDigitCharacter => {
+                write!(f, "invalid sheba number: contains non-digit characters after IR")
+            }
+            ShebaError::InvalidChecksum => write!(f, "invalid sheba number: checksum mismatch"),
+            ShebaError::InvalidBankCode(len) => {
+                write!(f, "invalid sheba number: bank code should have 3 digits, got {}", len)
+            }
+            ShebaError::AccountTooLong(len) => {
+                write!(f, "invalid sheba number: account should have at most 19 digits, got {}", len)
+            }
+        }
+    }
+}
+
+impl LocalizedError for ShebaError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "شماره شبا نامعتبر است: ")?;
+        match self {
+            ShebaError::InvalidLength(len) => write!(f, "باید 26 نویسه باشد، {} نویسه وارد شده", len),
+            ShebaError::InvalidCountryCode => write!(f, "باید با IR شروع شود"),
+            ShebaError::NonDigitCharacter => write!(f, "پس از IR فقط باید رقم بیاید"),
+            ShebaError::InvalidChecksum => write!(f, "رقم‌های کنترل مطابقت ندارند"),
+            ShebaError::InvalidBankCode(len) => write!(f, "کد بانک باید ۳ رقم باشد، {} رقم وارد شده", len),
+            ShebaError::AccountTooLong(len) => write!(f, "شماره حساب حداکثر ۱۹ رقم است، {} رقم وارد شده", len),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::bank::Bank;
+    use crate::bank_account::BankAccount;
+    use crate::fake::Rng;
+    use crate::sheba::{Sheba, ShebaError};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_random_shebas_are_valid() {
+        let mut rng = Rng::new(1);
+        for _ in 0..100 {
+            let sheba = Sheba::random(Bank::Melli, &mut rng);
+            assert_eq!(Sheba::try_from(sheba.as_str()), Ok(sheba.clone()));
+            assert_eq!(sheba.bank(), Some(Bank::Melli));
+        }
+    }
+
+    #[test]
+    fn test_validate_sheba() {
+        assert!(Sheba::try_from("IR062960000000100324200001").is_ok());
+        assert!(Sheba::try_from("IR820540102680020817909002").is_ok());
+        assert!("IR062960000000100324200001".parse::<Sheba>().is_ok());
+    }
+
+    #[test]
+    fn test_canonical_form() {
+        let expected = "IR062960000000100324200001";
+        assert_eq!(Sheba::try_from("ir06 2960 0000 0010 0324 2000 01").unwrap().as_str(), expected);
+        assert_eq!(Sheba::try_from("IR۰۶۲۹۶۰۰۰۰۰۰۰۱۰۰۳۲۴۲۰۰۰۰۱").unwrap().to_string(), expected);
+        assert_eq!(Sheba::try_from(expected).unwrap().to_persian_digits(), "IR۰۶۲۹۶۰۰۰۰۰۰۰۱۰۰۳۲۴۲۰۰۰۰۱");
+    }
+
+    #[test]
+    fn test_bank() {
+        assert_eq!(Sheba::try_from("IR820540102680020817909002").unwrap().bank(), Some(Bank::Parsian));
+        assert_eq!(Sheba::try_from("IR062960000000100324200001").unwrap().bank(), None);
+    }
+
+    #[test]
+    fn test_components() {
+        let sheba = Sheba::try_from("IR820540102680020817909002").unwrap();
+        assert_eq!(sheba.check_digits(), 82);
+        assert_eq!(sheba.bban(), "0540102680020817909002");
+        assert_eq!(sheba.bank_code(), "054");
+        assert_eq!(sheba.account_identifier(), "0102680020817909002");
+        assert_eq!(sheba.formatted(), "IR82 0540 1026 8002 0817 9090 02");
+        assert_eq!(format!("{:#}", sheba), sheba.formatted());
+        assert_eq!(Sheba::try_from(sheba.formatted()), Ok(sheba));
+    }
+
+    #[test]
+    fn test_account_number() {
+        let sheba = Sheba::from_bban("017", "1000000101234567001").unwrap();
+        assert_eq!(sheba.account_number(), Some(BankAccount::new(Bank::Melli, "0101234567001").unwrap()));
+        assert_eq!(sheba.account_type(), Some(1));
+
+        let sheba = Sheba::from_bban("012", "1234567890").unwrap();
+        assert_eq!(sheba.account_number().unwrap().as_str(), "1234567890");
+        assert_eq!(sheba.account_type(), Some(0));
+
+        assert_eq!(Sheba::from_bban("012", "0000100001234567890").unwrap().account_number(), None);
+        assert_eq!(Sheba::try_from("IR820540102680020817909002").unwrap().account_number(), None);
+        assert_eq!(Sheba::try_from("IR062960000000100324200001").unwrap().account_type(), None);
+    }
+
+    #[test]
+    fn test_from_bban() {
+        let sheba = Sheba::from_bban("054", "0102680020817909002").unwrap();
+        assert_eq!(sheba, Sheba::try_from("IR820540102680020817909002").unwrap());
+        assert_eq!(Sheba::from_bban("۲۹۶", "100324200001"), Sheba::try_from("IR062960000000100324200001"));
+
+        assert_eq!(Sheba::from_bban("54", "0102680020817909002"), Err(ShebaError::InvalidBankCode(2)));
+        assert_eq!(Sheba::from_bban("05", "40102680020817909002"), Err(ShebaError::InvalidBankCode(2)));
+        assert_eq!(Sheba::from_bban("0540", "102680020817909002"), Err(ShebaError::InvalidBankCode(4)));
+        assert_eq!(Sheba::from_bban("054", "10102680020817909002"), Err(ShebaError::AccountTooLong(20)));
+        assert_eq!(Sheba::from_bban("054", "12-34"), Err(ShebaError::NonDigitCharacter));
+    }
+
+    #[test]
+    fn test_invalid_sheba() {
+        assert_eq!(Sheba::try_from("IR06296000000010032420000"), Err(ShebaError::InvalidLength(25)));
+        assert_eq!(Sheba::try_from("DE062960000000100324200001"), Err(ShebaError::InvalidCountryCode));
+        assert_eq!(Sheba::try_from("IR06296000000010032420000A"), Err(ShebaError::NonDigitCharacter));
+        assert_eq!(Sheba::try_from("IR072960000000100324200001"), Err(ShebaError::InvalidChecksum));
+    }
+}
@@ -0,0 +1,263 @@
+use alloc::format;
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::locale::LocalizedError;
+use crate::normalize::{digits, digits_to_string, is_separator, normalize_digit, to_persian_digits, DigitsError};
+
+/// Social security insurance number (شماره بیمه) issued by the Tamin Ejtemaei
+/// organization, as 10 digits.
+///
+/// Older numbers were issued with fewer digits and are zero-padded on the left, as the
+/// organization's services do. No check digit is published, so only the shape is validated.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct InsuranceNumber(String);
+
+impl TryFrom<&str> for InsuranceNumber {
+    type Error = InsuranceNumberError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let number: String = value.chars().filter(|c| !is_separator(*c)).map(normalize_digit).collect();
+        if !number.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(InsuranceNumberError::NonDigitCharacter);
+        }
+        if !(1..=10).contains(&number.len()) {
+            return Err(InsuranceNumberError::InvalidLength(number.len()));
+        }
+        if number.bytes().all(|b| b == b'0') {
+            return Err(InsuranceNumberError::Zero);
+        }
+        Ok(InsuranceNumber(format!("{:0>10}", number)))
+    }
+}
+
+impl TryFrom<String> for InsuranceNumber {
+    type Error = InsuranceNumberError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        InsuranceNumber::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for InsuranceNumber {
+    type Error = InsuranceNumberError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        InsuranceNumber::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<u64> for InsuranceNumber {
+    type Error = InsuranceNumberError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        InsuranceNumber::try_from(format!("{}", value))
+    }
+}
+
+impl FromStr for InsuranceNumber {
+    type Err = InsuranceNumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        InsuranceNumber::try_from(s)
+    }
+}
+
+impl InsuranceNumber {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl AsRef<str> for InsuranceNumber {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for InsuranceNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum InsuranceNumberError {
+    InvalidLength(usize),
+    NonDigitCharacter,
+    Zero,
+}
+
+impl Error for InsuranceNumberError {}
+
+impl Display for InsuranceNumberError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            InsuranceNumberError::InvalidLength(len) => {
+                write!(f, "invalid insurance number: expected up to 10 digits, got {}", len)
+            }
+            InsuranceNumberError::NonDigitCharacter => {
+                write!(f, "invalid insurance number: contains non-digit characters")
+            }
+            InsuranceNumberError::Zero => write!(f, "invalid insurance number: number is zero"),
+        }
+    }
+}
+
+impl LocalizedError for InsuranceNumberError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "شماره بیمه نامعتبر است: ")?;
+        match self {
+            InsuranceNumberError::InvalidLength(len) => write!(f, "حداکثر ۱۰ رقم مجاز است، {} رقم وارد شده", len),
+            InsuranceNumberError::NonDigitCharacter => write!(f, "فقط باید شامل رقم باشد"),
+            InsuranceNumberError::Zero => write!(f, "شماره صفر است"),
+        }
+    }
+}
+
+/// The 10-digit workshop code (کد کارگاه) an employer is registered under with the
+/// Tamin Ejtemaei organization, quoted on monthly insurance lists.
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub struct WorkshopCode(String);
+
+impl TryFrom<&str> for WorkshopCode {
+    type Error = WorkshopCodeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let digits: [u32; 10] = digits(value.chars().filter(|c| !is_separator(*c))).map_err(|err| match err {
+            DigitsError::Length(len) => WorkshopCodeError::InvalidLength(len),
+            DigitsError::NonDigit => WorkshopCodeError::NonDigitCharacter,
+        })?;
+
+        if digits.iter().all(|d| *d == 0) {
+            return Err(WorkshopCodeError::Zero);
+        }
+        Ok(WorkshopCode(digits_to_string(&digits)))
+    }
+}
+
+impl TryFrom<String> for WorkshopCode {
+    type Error = WorkshopCodeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        WorkshopCode::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for WorkshopCode {
+    type Error = WorkshopCodeError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        WorkshopCode::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<u64> for WorkshopCode {
+    type Error = WorkshopCodeError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        WorkshopCode::try_from(format!("{:010}", value))
+    }
+}
+
+impl FromStr for WorkshopCode {
+    type Err = WorkshopCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        WorkshopCode::try_from(s)
+    }
+}
+
+impl WorkshopCode {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn to_persian_digits(&self) -> String {
+        to_persian_digits(self.as_str())
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl AsRef<str> for WorkshopCode {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for WorkshopCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum WorkshopCodeError {
+    InvalidLength(usize),
+    NonDigitCharacter,
+    Zero,
+}
+
+impl Error for WorkshopCodeError {}
+
+impl Display for WorkshopCodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            WorkshopCodeError::InvalidLength(len) => {
+                write!(f, "invalid workshop code: expected 10 digits, got {}", len)
+            }
+            WorkshopCodeError::NonDigitCharacter => write!(f, "invalid workshop code: contains non-digit characters"),
+            WorkshopCodeError::Zero => write!(f, "invalid workshop code: all digits are zero"),
+        }
+    }
+}
+
+impl LocalizedError for WorkshopCodeError {
+    fn fmt_persian(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "کد کارگاه نامعتبر است: ")?;
+        match self {
+            WorkshopCodeError::InvalidLength(len) => write!(f, "باید ۱۰ رقم باشد، {} رقم وارد شده", len),
+            WorkshopCodeError::NonDigitCharacter => write!(f, "فقط باید شامل رقم باشد"),
+            WorkshopCodeError::Zero => write!(f, "همه ارقام صفر است"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::social_security::{InsuranceNumber, InsuranceNumberError, WorkshopCode, WorkshopCodeError};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_validate_insurance_number() {
+        assert_eq!(InsuranceNumber::try_from("۱۲۳۴۵۶۷۸۹۰").unwrap().as_str(), "1234567890");
+        assert_eq!(InsuranceNumber::try_from("12345678").unwrap().as_str(), "0012345678");
+        assert_eq!(InsuranceNumber::try_from(12345678u64), InsuranceNumber::try_from("0012345678"));
+        assert_eq!(InsuranceNumber::try_from("12345678901"), Err(InsuranceNumberError::InvalidLength(11)));
+        assert_eq!(InsuranceNumber::try_from("1234-5678a"), Err(InsuranceNumberError::NonDigitCharacter));
+        assert_eq!(InsuranceNumber::try_from("0000"), Err(InsuranceNumberError::Zero));
+    }
+
+    #[test]
+    fn test_validate_workshop_code() {
+        assert_eq!(WorkshopCode::try_from("۱۲۳ ۴۵۶۷۸۹۰").unwrap().to_string(), "1234567890");
+        assert_eq!(WorkshopCode::try_from(234567890u64).unwrap().as_str(), "0234567890");
+        assert_eq!(WorkshopCode::try_from("123456789"), Err(WorkshopCodeError::InvalidLength(9)));
+        assert_eq!(WorkshopCode::try_from("000000000x"), Err(WorkshopCodeError::NonDigitCharacter));
+        assert_eq!(WorkshopCode::try_from("0000000000"), Err(WorkshopCodeError::Zero));
+    }
+}
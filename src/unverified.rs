@@ -0,0 +1,79 @@
+use alloc::string::String;
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use crate::national_id::NationalId;
+
+/// User input meant to become a `T` but not checked yet.
+///
+/// It can be stored and passed around, but the only way to get a `T` out of it is
+/// [`Unverified::verify`], so functions that take `T` can never receive unchecked input.
+pub struct Unverified<T> {
+    raw: String,
+    target: PhantomData<fn() -> T>,
+}
+
+/// Unchecked national id input, e.g. a form field.
+pub type RawNationalId = Unverified<NationalId>;
+
+impl<T: FromStr> Unverified<T> {
+    pub fn new(raw: impl Into<String>) -> Self {
+        Unverified { raw: raw.into(), target: PhantomData }
+    }
+
+    /// The input exactly as received.
+    pub fn as_raw(&self) -> &str {
+        &self.raw
+    }
+
+    pub fn verify(&self) -> Result<T, T::Err> {
+        self.raw.parse()
+    }
+}
+
+impl<T> Clone for Unverified<T> {
+    fn clone(&self) -> Self {
+        Unverified { raw: self.raw.clone(), target: PhantomData }
+    }
+}
+
+impl<T> PartialEq for Unverified<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl<T> Eq for Unverified<T> {}
+
+/// Omits the raw input, which may hold personal data.
+impl<T> Debug for Unverified<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Unverified").finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::national_id::{NationalId, NationalIdError};
+    use crate::sheba::Sheba;
+    use crate::unverified::{RawNationalId, Unverified};
+    use std::convert::TryFrom;
+
+    fn store(id: NationalId) -> String {
+        id.to_string()
+    }
+
+    #[test]
+    fn test_verify_before_use() {
+        let raw = RawNationalId::new(" 40010007 ");
+        assert_eq!(raw.as_raw(), " 40010007 ");
+        assert_eq!(raw.verify().map(store), Ok("0040010007".to_string()));
+        assert_eq!(RawNationalId::new("0040010008").verify(), Err(NationalIdError::InvalidCheckDigit { expected: 7, found: 8 }));
+
+        let sheba: Unverified<Sheba> = Unverified::new(String::from("IR062960000000100324200001"));
+        assert_eq!(sheba.verify(), Sheba::try_from("IR062960000000100324200001"));
+        assert_eq!(sheba.clone(), sheba);
+        assert_eq!(format!("{:?}", sheba), "Unverified { .. }");
+    }
+}
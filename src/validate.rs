@@ -0,0 +1,115 @@
+use crate::fields::FieldErrors;
+
+/// A struct whose fields hold identifiers to validate together, usually implemented
+/// with [`impl_validate!`](crate::impl_validate).
+pub trait Validate {
+    /// Checks every field, returning the errors of all invalid ones keyed by field name.
+    fn validate(&self) -> Result<(), FieldErrors>;
+}
+
+/// Implements [`Validate`] for a struct, checking each listed field as the identifier
+/// named by its [`IdentifierKind::name`](crate::IdentifierKind::name):
+///
+/// ```
+/// use inin::Validate;
+///
+/// struct Signup {
+///     code: String,
+///     iban: String,
+///     name: String,
+/// }
+///
+/// inin::impl_validate!(Signup { code: national_id, iban: sheba });
+///
+/// let signup = Signup {
+///     code: "0040010008".to_string(),
+///     iban: "IR062960000000100324200001".to_string(),
+///     name: "Sara".to_string(),
+/// };
+/// let errors = signup.validate().unwrap_err();
+/// assert_eq!(errors.to_string(), "code: invalid iranian national id number: check digit should be 7, found 8");
+/// ```
+///
+/// Fields must implement `AsRef<str>`; fields that are not listed are not checked.
+#[macro_export]
+macro_rules! impl_validate {
+    ($type:ty { $($field:ident : $kind:ident),+ $(,)? }) => {
+        impl $crate::Validate for $type {
+            fn validate(&self) -> ::core::result::Result<(), $crate::FieldErrors> {
+                let mut errors = $crate::FieldErrors::default();
+                $(
+                    errors.check_identifier::<$crate::__identifier_type!($kind)>(
+                        stringify!($field),
+                        ::core::convert::AsRef::<str>::as_ref(&self.$field),
+                    );
+                )+
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(errors)
+                }
+            }
+        }
+    };
+}
+
+/// The identifier type of a kind name, for [`impl_validate!`](crate::impl_validate).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __identifier_type {
+    (national_id) => { $crate::NationalId };
+    (legal_national_id) => { $crate::LegalNationalId };
+    (foreign_national_id) => { $crate::ForeignNationalId };
+    (person_id) => { $crate::PersonId };
+    (economic_code) => { $crate::EconomicCode };
+    (sheba) => { $crate::Sheba };
+    (bank_card) => { $crate::BankCard };
+    (postal_code) => { $crate::PostalCode };
+    (mobile_number) => { $crate::MobileNumber };
+    (landline_number) => { $crate::LandlineNumber };
+    (isin) => { $crate::Isin };
+    (license_plate) => { $crate::LicensePlate };
+    (registration_number) => { $crate::RegistrationNumber };
+    (sayad_id) => { $crate::SayadId };
+    (birth_certificate_number) => { $crate::BirthCertificateNumber };
+    (insurance_number) => { $crate::InsuranceNumber };
+    (workshop_code) => { $crate::WorkshopCode };
+    (driving_license) => { $crate::DrivingLicense };
+    (passport_number) => { $crate::PassportNumber };
+    (national_card_serial) => { $crate::NationalCardSerial };
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::fields::FieldError;
+    use crate::mobile_number::MobileNumberError;
+    use crate::validate::Validate;
+
+    struct Employee<'a> {
+        national_id: String,
+        mobile: &'a str,
+        insurance: String,
+        note: String,
+    }
+
+    impl_validate!(Employee<'_> { national_id: national_id, mobile: mobile_number, insurance: insurance_number });
+
+    #[test]
+    fn test_impl_validate() {
+        let mut employee = Employee {
+            national_id: "۰۰۴۰۰۱۰۰۰۷".to_string(),
+            mobile: "09123456789",
+            insurance: "12345678".to_string(),
+            note: String::new(),
+        };
+        assert_eq!(employee.validate(), Ok(()));
+
+        employee.mobile = "0912";
+        employee.national_id = "abc".to_string();
+        let errors = employee.validate().unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors.get("mobile"), Some(&FieldError::Invalid(MobileNumberError::InvalidLength(4).into())));
+        assert!(errors.get("national_id").is_some());
+        assert!(employee.note.is_empty());
+    }
+}